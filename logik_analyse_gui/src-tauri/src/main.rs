@@ -13,7 +13,7 @@ use lazy_static::lazy_static;
 use tauri::utils::resources::ResourcePaths;
 use logik_lib::aussagen::*;
 use logik_lib::aussagen::parsing::parse_function;
-use logik_lib::aussagen::structures::{AussagenFunktion, FormelKontext};
+use logik_lib::aussagen::structures::{werte_to_string, AussagenFunktion, FormelKontext};
 
 struct MyState {
     kontext: FormelKontext,
@@ -46,7 +46,23 @@ fn main() {
             get_wahrheitstabelle_cmd,
             getFormel,
             check_formel,
-            is_aequivalent
+            is_aequivalent,
+            get_nnf,
+            get_knf,
+            get_dnf,
+            get_kanonische_form,
+            minimiere,
+            get_karnaugh,
+            vereinfache,
+            get_anzahl_modelle,
+            get_resolution,
+            get_tableau,
+            get_sequenzbeweis,
+            get_minimales_modell,
+            get_zwei_sat,
+            get_post_klassen,
+            ist_vollstaendig,
+            get_gatterform
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -76,12 +92,379 @@ fn getFormel(state: tauri::State<'_, Mutex<MyState>>, name: &str, is_utf: bool)
     }
 }
 
+#[tauri::command(rename_all = "snake_case")]
+fn get_nnf(state: tauri::State<'_, Mutex<MyState>>, name: &str, is_utf: bool) -> Result<String, String> {
+    match state.lock() {
+        Ok(state) => {
+            let formel = state.get(String::from(name));
+            if formel.is_none() {
+                return Err(format!("Formel {} nicht gefunden", name));
+            }
+            let nnf = formel.unwrap().to_nnf(&state.kontext);
+            if is_utf {
+                Ok(nnf.to_utf_string())
+            } else {
+                Ok(nnf.to_ascii_string())
+            }
+        }
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+#[tauri::command(rename_all = "snake_case")]
+fn get_knf(
+    state: tauri::State<'_, Mutex<MyState>>,
+    name: &str,
+    tseitin: bool,
+    is_utf: bool,
+) -> Result<serde_json::Value, String> {
+    match state.lock() {
+        Ok(state) => {
+            let formel = state.get(String::from(name));
+            if formel.is_none() {
+                return Err(format!("Formel {} nicht gefunden", name));
+            }
+            let formel = formel.unwrap();
+            let knf = if tseitin {
+                formel.to_knf_tseitin(&state.kontext)
+            } else {
+                formel.to_knf(&state.kontext)
+            };
+            let form = knf.to_funktion();
+            Ok(serde_json::json!({
+                "formel": if is_utf { form.to_utf_string() } else { form.to_ascii_string() },
+                "klauseln": knf.klauseln,
+            }))
+        }
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+#[tauri::command(rename_all = "snake_case")]
+fn get_dnf(state: tauri::State<'_, Mutex<MyState>>, name: &str, is_utf: bool) -> Result<String, String> {
+    match state.lock() {
+        Ok(state) => {
+            let formel = state.get(String::from(name));
+            if formel.is_none() {
+                return Err(format!("Formel {} nicht gefunden", name));
+            }
+            let dnf = formel.unwrap().to_dnf(&state.kontext);
+            if is_utf {
+                Ok(dnf.to_utf_string())
+            } else {
+                Ok(dnf.to_ascii_string())
+            }
+        }
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+#[tauri::command(rename_all = "snake_case")]
+fn get_kanonische_form(
+    state: tauri::State<'_, Mutex<MyState>>,
+    name: &str,
+    dnf: bool,
+    is_utf: bool,
+) -> Result<String, String> {
+    match state.lock() {
+        Ok(state) => {
+            let formel = state.get(String::from(name));
+            if formel.is_none() {
+                return Err(format!("Formel {} nicht gefunden", name));
+            }
+            let form = if dnf {
+                formel.unwrap().kanonische_dnf(&state.kontext)
+            } else {
+                formel.unwrap().kanonische_knf(&state.kontext)
+            }
+            .map_err(|e| e.to_string())?;
+            if is_utf {
+                Ok(form.to_utf_string())
+            } else {
+                Ok(form.to_ascii_string())
+            }
+        }
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+#[tauri::command]
+fn minimiere(state: tauri::State<'_, Mutex<MyState>>, name: &str) -> Result<serde_json::Value, String> {
+    match state.lock() {
+        Ok(state) => {
+            let formel = state.get(String::from(name));
+            if formel.is_none() {
+                return Err(format!("Formel {} nicht gefunden", name));
+            }
+            let ergebnis = minimierung::minimiere(&state.kontext, formel.unwrap()).map_err(|e| e.to_string())?;
+            let als_text = |implikanten: &[minimierung::Implikant]| -> Vec<String> {
+                implikanten
+                    .iter()
+                    .map(|implikant| ergebnis.to_funktion(implikant).to_utf_string())
+                    .collect()
+            };
+            Ok(serde_json::json!({
+                "primimplikanten": als_text(&ergebnis.primimplikanten),
+                "essentielle_primimplikanten": als_text(&ergebnis.essentielle_primimplikanten),
+                "minimalform": ergebnis.minimalform().to_utf_string(),
+            }))
+        }
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+#[tauri::command]
+fn get_karnaugh(
+    state: tauri::State<'_, Mutex<MyState>>,
+    name: &str,
+    gruppen: bool,
+) -> Result<karnaugh::KarnaughDiagramm, String> {
+    match state.lock() {
+        Ok(state) => {
+            let formel = state.get(String::from(name));
+            if formel.is_none() {
+                return Err(format!("Formel {} nicht gefunden", name));
+            }
+            let formel = formel.unwrap();
+            let diagramm = karnaugh::KarnaughDiagramm::new(&state.kontext, formel).map_err(|e| e.to_string())?;
+            if gruppen {
+                let ergebnis = minimierung::minimiere(&state.kontext, formel).map_err(|e| e.to_string())?;
+                diagramm.mit_gruppen(&ergebnis).map_err(|e| e.to_string())
+            } else {
+                Ok(diagramm)
+            }
+        }
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+#[tauri::command]
+fn vereinfache(
+    state: tauri::State<'_, Mutex<MyState>>,
+    name: &str,
+    is_utf: bool,
+) -> Result<Vec<serde_json::Value>, String> {
+    match state.lock() {
+        Ok(state) => {
+            let formel = state.get(String::from(name));
+            if formel.is_none() {
+                return Err(format!("Formel {} nicht gefunden", name));
+            }
+            let als_text = |funktion: &AussagenFunktion| {
+                if is_utf {
+                    funktion.to_utf_string()
+                } else {
+                    funktion.to_ascii_string()
+                }
+            };
+            Ok(formel
+                .unwrap()
+                .vereinfache()
+                .schritte
+                .iter()
+                .map(|umformung| {
+                    serde_json::json!({
+                        "gesetz": umformung.gesetz.to_string(),
+                        "vorher": als_text(&umformung.vorher),
+                        "nachher": als_text(&umformung.nachher),
+                        "formel": als_text(&umformung.formel),
+                    })
+                })
+                .collect())
+        }
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Die Anzahl wird als Text zurückgegeben, weil sie nicht in eine JSON-Zahl passen muss.
+#[tauri::command]
+fn get_anzahl_modelle(state: tauri::State<'_, Mutex<MyState>>, name: &str) -> Result<String, String> {
+    match state.lock() {
+        Ok(state) => {
+            let formel = state.get(String::from(name));
+            if formel.is_none() {
+                return Err(format!("Formel {} nicht gefunden", name));
+            }
+            match anzahl_modelle(&state.kontext, formel.unwrap()) {
+                Some(anzahl) => Ok(anzahl.to_string()),
+                None => Err(String::from("Mindestens 2^128 Modelle")),
+            }
+        }
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Gibt `None` zurück, wenn die Formel erfüllbar ist und es deshalb keine Widerlegung gibt.
+#[tauri::command]
+fn get_resolution(
+    state: tauri::State<'_, Mutex<MyState>>,
+    name: &str,
+) -> Result<Option<resolution::Resolutionsbeweis>, String> {
+    match state.lock() {
+        Ok(state) => {
+            let formel = state.get(String::from(name));
+            if formel.is_none() {
+                return Err(format!("Formel {} nicht gefunden", name));
+            }
+            Ok(formel.unwrap().widerlege(&state.kontext))
+        }
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+#[tauri::command]
+fn get_tableau(state: tauri::State<'_, Mutex<MyState>>, name: &str) -> Result<tableau::Tableau, String> {
+    match state.lock() {
+        Ok(state) => {
+            let formel = state.get(String::from(name));
+            if formel.is_none() {
+                return Err(format!("Formel {} nicht gefunden", name));
+            }
+            Ok(tableau::Tableau::new(&state.kontext, formel.unwrap()))
+        }
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+#[tauri::command]
+fn get_minimales_modell(
+    state: tauri::State<'_, Mutex<MyState>>,
+    name: &str,
+) -> Result<Option<HashMap<String, bool>>, String> {
+    match state.lock() {
+        Ok(state) => {
+            let formel = state.get(String::from(name));
+            if formel.is_none() {
+                return Err(format!("Formel {} nicht gefunden", name));
+            }
+            formel
+                .unwrap()
+                .minimales_modell(&state.kontext)
+                .map_err(|e| e.to_string())
+        }
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+#[tauri::command]
+fn get_zwei_sat(
+    state: tauri::State<'_, Mutex<MyState>>,
+    name: &str,
+) -> Result<Option<zwei_sat::ZweiSat>, String> {
+    match state.lock() {
+        Ok(state) => {
+            let formel = state.get(String::from(name));
+            if formel.is_none() {
+                return Err(format!("Formel {} nicht gefunden", name));
+            }
+            Ok(formel
+                .unwrap()
+                .to_zwei_knf(&state.kontext)
+                .and_then(|menge| zwei_sat::loese_zwei_sat(&menge)))
+        }
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+#[tauri::command]
+fn get_post_klassen(state: tauri::State<'_, Mutex<MyState>>, name: &str) -> Result<post::PostKlassen, String> {
+    match state.lock() {
+        Ok(state) => {
+            let formel = state.get(String::from(name));
+            if formel.is_none() {
+                return Err(format!("Formel {} nicht gefunden", name));
+            }
+            post::PostKlassen::new(&state.kontext, formel.unwrap()).map_err(|e| e.to_string())
+        }
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+#[tauri::command]
+fn ist_vollstaendig(
+    state: tauri::State<'_, Mutex<MyState>>,
+    namen: Vec<String>,
+) -> Result<post::Vollstaendigkeit, String> {
+    match state.lock() {
+        Ok(state) => {
+            let mut formeln = Vec::new();
+            for name in &namen {
+                if state.get(name.clone()).is_none() {
+                    return Err(format!("Formel {} nicht gefunden", name));
+                }
+                formeln.push(AussagenFunktion::VARIABEL(name.clone()));
+            }
+            let formeln: Vec<&AussagenFunktion> = formeln.iter().collect();
+            post::Vollstaendigkeit::new(&state.kontext, &formeln).map_err(|e| e.to_string())
+        }
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+#[tauri::command(rename_all = "snake_case")]
+fn get_gatterform(
+    state: tauri::State<'_, Mutex<MyState>>,
+    name: &str,
+    nor: bool,
+    teilen: bool,
+    is_utf: bool,
+) -> Result<serde_json::Value, String> {
+    match state.lock() {
+        Ok(state) => {
+            let formel = state.get(String::from(name));
+            if formel.is_none() {
+                return Err(format!("Formel {} nicht gefunden", name));
+            }
+            let typ = if nor { gatter::Gattertyp::Nor } else { gatter::Gattertyp::Nand };
+            let schaltung = gatter::Schaltung::aus_funktion(&state.kontext, formel.unwrap(), typ);
+            if teilen {
+                return Ok(serde_json::json!({
+                    "anzahl_gatter": schaltung.gatter.len(),
+                    "schaltung": schaltung,
+                }));
+            }
+            let form = schaltung.to_funktion();
+            Ok(serde_json::json!({
+                "formel": if is_utf { form.to_utf_string() } else { form.to_ascii_string() },
+                "anzahl_gatter": gatter::anzahl_gatter(&form),
+            }))
+        }
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+#[tauri::command]
+fn get_sequenzbeweis(
+    state: tauri::State<'_, Mutex<MyState>>,
+    links: Vec<String>,
+    rechts: Vec<String>,
+) -> Result<sequenzen::Sequenzbeweis, String> {
+    match state.lock() {
+        Ok(state) => {
+            let mut formeln: Vec<Vec<&AussagenFunktion>> = Vec::new();
+            for namen in [&links, &rechts] {
+                let mut seite = Vec::new();
+                for name in namen {
+                    match state.get(name.clone()) {
+                        Some(formel) => seite.push(formel),
+                        None => return Err(format!("Formel {} nicht gefunden", name)),
+                    }
+                }
+                formeln.push(seite);
+            }
+            Ok(sequenzen::Sequenzbeweis::new(&state.kontext, &formeln[0], &formeln[1]))
+        }
+        Err(e) => Err(e.to_string()),
+    }
+}
+
 #[tauri::command]
 async fn check_formel(
     mut state: tauri::State<'_, Mutex<MyState>>,
     input: &str,
 ) -> Result<String, String> {
-    let funktion = parse_function(&String::from(input))?;
+    let funktion = parse_function(input)
+        .map_err(|fehler| format!("{}\n{}", fehler.markiere(input), fehler))?;
     let utf = funktion.to_utf_string();
     Ok(utf)
 }
@@ -137,70 +520,36 @@ async fn get_wahrheitstabelle_cmd(
 
 #[tauri::command]
 async fn is_aequivalent(
-    mut state: tauri::State<'_, Mutex<MyState>>,
+    state: tauri::State<'_, Mutex<MyState>>,
     namen: Vec<String>,
 ) -> Result<String, String> {
-    println!("is_aequivalent");
-    let mut formeln = Vec::new();
-
     match state.lock() {
         Ok(state) => {
-            let mut map = HashMap::new();
-            for ele in &namen {
-                let func = state.get(ele.clone());
-                if func.is_some() {
-                    let func = func.unwrap();
-                    formeln.push(func);
-                    for name2 in &namen {
-                        let func = state.get(name2.clone());
-                        if func.is_some() {
-                                if !formeln.contains(&func.unwrap()) {
-                                map.insert(format!("{} ≣ {}", ele, name2), true);
-                            }
-                        }
+            let mut zeilen = Vec::new();
+            for (i, links) in namen.iter().enumerate() {
+                for rechts in &namen[i + 1..] {
+                    let (formel_links, formel_rechts) = match (state.get(links.clone()), state.get(rechts.clone())) {
+                        (Some(formel_links), Some(formel_rechts)) => (formel_links, formel_rechts),
+                        _ => continue,
+                    };
+                    let aequivalenz = pruefe_aequivalenz(
+                        &state.kontext,
+                        vec![(links.as_str(), formel_links), (rechts.as_str(), formel_rechts)],
+                    );
+                    match aequivalenz.gegenbeispiele.first() {
+                        None => zeilen.push(format!("{} ≡ {}", links, rechts)),
+                        Some(belegung) => zeilen.push(format!(
+                            "{} ≢ {}: Gegenbeispiel {} ({})",
+                            links,
+                            rechts,
+                            werte_to_string(&belegung.werte),
+                            werte_to_string(&belegung.ergebnisse)
+                        )),
                     }
                 }
             }
-
-            let tabelle = get_wahrheitstabelle(&state.kontext, formeln);
-            for ele in tabelle.belegungen {
-                for tupel in &ele.ergebnisse {
-                    for tupel2 in &ele.ergebnisse {
-                        if map.contains_key(&format!("{} ≣ {}", tupel.0, tupel2.0)) {
-                            if (tupel.1 != tupel2.1) {
-                                map.insert(format!("{} ≣ {}", tupel.0, tupel2.0), false);
-                            }
-                        }
-                    }
-                }
-            }
-
-            let mut s = String::new();
-            for ele in map {
-                if s.is_empty() {
-                    s = format!("{}", ele.0.replace("≣", {
-                        if ele.1 {
-                            "≡"
-                        }else {
-                            "≢"
-                        }
-                    }));
-                } else {
-                    s = format!("{}\n{}", s, ele.0.replace("≣", {
-                        if ele.1 {
-                            "="
-                        }else {
-                            "≠"
-                        }
-                    }));
-                }
-            }
-            Ok(s)
-        }
-        Err(e) => {
-            let r = e.get_ref();
-            drop(r);
-            return Err(e.to_string());
+            Ok(zeilen.join("\n"))
         }
+        Err(e) => Err(e.to_string()),
     }
 }
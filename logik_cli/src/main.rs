@@ -4,10 +4,10 @@ use std::io::{stdin, Error};
 use std::time::Instant;
 use clap::Parser;
 use logik_lib::aussagen::structures::FormelKontext;
-use logik_lib::script::parse_line;
+use logik_lib::script::{Script, ScriptAction};
 use termimad::*;
 
-static SYNTAX_HELP: &'static str = include_str!("../resources/syntax_help.md");
+static SYNTAX_HELP: &str = include_str!("../resources/syntax_help.md");
 
 
 #[derive(Parser, Debug)]
@@ -33,6 +33,7 @@ fn main() {
 
 pub fn read_script(args: CLIOptions) -> Result<(), Error> {
     let mut kontext: FormelKontext = FormelKontext::new();
+    let mut script = Script::new();
     let lines = stdin().lines();
     let  time = Instant::now();
     for line in lines {
@@ -40,13 +41,17 @@ pub fn read_script(args: CLIOptions) -> Result<(), Error> {
         if args.print_instructions {
             println!("{}",line);
         }
-        match parse_line(line.as_str(), &mut kontext) {
+        match script.parse_line(line.as_str(), &mut kontext) {
+            Ok(ScriptAction::Gepuffert()) => {}
             Ok(action) => println!("{}", action),
             Err(error) => {
-                println!("{:?}",error)
+                println!("{}", error)
             },
         }
     }
+    if let Err(error) = script.beende() {
+        println!("{}", error)
+    }
     println!("Finished in {:?}", time.elapsed());
 
     Ok(())
@@ -1,15 +1,30 @@
-use parsing::ParseError::*;
-use parsing::ParseOption::{AND, NOTHING, OR, UNSPECIFIED, VARIABLE};
-use slab_tree::{NodeId, Tree, TreeBuilder};
-
 use std::collections::{HashMap, HashSet};
-use std::ptr::{addr_of, addr_of_mut};
-use parsing::{Parsed, ParseError, ParseOption};
 
-use self::structures::{AussagenFunktion, Belegung, FormelKontext, Wahrheitstabelle};
+use self::bdd::BddManager;
+use self::sat::SatSolver;
+use self::structures::{Aequivalenz, AussagenFunktion, Belegung, FormelKontext, Wahrheitstabelle};
 
 pub mod structures;
+pub mod lexer;
+pub mod klauseln;
 pub mod parsing;
+pub mod normalformen;
+pub mod minimierung;
+pub mod karnaugh;
+pub mod vereinfachung;
+pub mod bdd;
+pub mod sat;
+pub mod klassifikation;
+pub mod folgerung;
+pub mod modelle;
+pub mod resolution;
+pub mod tableau;
+pub mod deduktion;
+pub mod sequenzen;
+pub mod horn;
+pub mod zwei_sat;
+pub mod post;
+pub mod gatter;
 
 pub fn get_belegung(
     kontext: &FormelKontext,
@@ -19,14 +34,14 @@ pub fn get_belegung(
     let mut ergebnisse = HashMap::new();
     for aussagen_funktion in funktionen {
         ergebnisse.insert(
-            kontext.get_key(&aussagen_funktion).unwrap(),
-            aussagen_funktion.result(kontext, &werte, false),
+            kontext.get_key(aussagen_funktion).unwrap(),
+            aussagen_funktion.result(kontext, werte, false),
         );
     }
 
     Belegung {
         werte: werte.clone(),
-        ergebnisse: ergebnisse,
+        ergebnisse,
     }
 }
 
@@ -37,8 +52,7 @@ fn get_all_keys<'a>(kontext: &'a FormelKontext, funktionen: &'a Vec<&AussagenFun
         set.extend(keys);
         keys = set;
     }
-    let mut keys: Vec<&String> = Vec::from_iter(keys.into_iter());
-    keys
+    Vec::from_iter(keys)
 }
 
 fn call_for_every_belegung<T>(kontext: &FormelKontext,  keys: &mut Vec<&String>, funktionen: &Vec<&AussagenFunktion>, map: &mut HashMap<String, bool>, funktion: fn(Belegung) -> T, joiner: fn(T,T) -> T) -> T {
@@ -46,14 +60,14 @@ fn call_for_every_belegung<T>(kontext: &FormelKontext,  keys: &mut Vec<&String>,
     match key {
         Some(key) => {
             map.insert(key.clone(), false);
-            let mut erstes_element: T = call_for_every_belegung(kontext, keys, funktionen, map, funktion, joiner);
+            let erstes_element: T = call_for_every_belegung(kontext, keys, funktionen, map, funktion, joiner);
             map.insert(key.clone(), true);
-            let mut zweites_element: T = call_for_every_belegung(kontext, keys, funktionen, map, funktion, joiner);
+            let zweites_element: T = call_for_every_belegung(kontext, keys, funktionen, map, funktion, joiner);
             keys.push(key);
             joiner(erstes_element, zweites_element)
         }
         None =>  {
-            let belegung = get_belegung(kontext, &funktionen, map);
+            let belegung = get_belegung(kontext, funktionen, map);
             funktion(belegung)
         }
     }
@@ -71,8 +85,7 @@ funktionen: Vec<&AussagenFunktion>,
 
 
 fn to_tabelle(belegung: Belegung) -> Wahrheitstabelle {
-    let mut belegungen = Vec::new();
-    belegungen.push(belegung);
+    let belegungen = vec![belegung];
 
     Wahrheitstabelle {
         belegungen,
@@ -84,25 +97,69 @@ fn join_tabellen(mut t1: Wahrheitstabelle, t2: Wahrheitstabelle) -> Wahrheitstab
     t1
 }
 
+/// Prüft mit BDDs, ob alle Funktionen äquivalent sind. Da ein Bdd kanonisch ist, reicht ein Vergleich der Wurzeln.
 pub fn is_aequivalent(kontext: &FormelKontext, funktionen: Vec<&AussagenFunktion>) -> bool {
-    call_for_every_belegung(kontext, &mut get_all_keys(kontext,&funktionen), &funktionen, &mut HashMap::new(), is_belegung_aequivalent, join_bool_and)
+    let mut manager = BddManager::new();
+    let mut bdds = funktionen.iter().map(|funktion| manager.aus_funktion(kontext, funktion));
+    match bdds.next() {
+        None => true,
+        Some(erster) => bdds.all(|bdd| bdd == erster),
+    }
 }
 
-fn is_belegung_aequivalent(belegung: Belegung) -> bool {
-    let mut iter = belegung.ergebnisse.into_iter();
-    let option = iter.next();
-    if option.is_none() {
-        return true;
-    }
-    let value = option.unwrap().1;
-    for tupel in iter {
-        if  tupel.1 != value {
-            return false;
+/// Prüft mit BDDs, ob alle Funktionen zur ersten äquivalent sind, und sucht sonst Gegenbeispiele.
+/// Die Ergebnisse der Gegenbeispiele sind nach den übergebenen Namen benannt.
+pub fn pruefe_aequivalenz(kontext: &FormelKontext, funktionen: Vec<(&str, &AussagenFunktion)>) -> Aequivalenz {
+    let mut manager = BddManager::new();
+    let bdds: Vec<_> = funktionen
+        .iter()
+        .map(|(_, funktion)| manager.aus_funktion(kontext, funktion))
+        .collect();
+    let nur_funktionen: Vec<&AussagenFunktion> = funktionen.iter().map(|(_, funktion)| *funktion).collect();
+    let variablen = get_all_keys(kontext, &nur_funktionen);
+    let mut gegenbeispiele = Vec::new();
+    for bdd in bdds.iter().skip(1) {
+        let unterschied = manager.xor(bdds[0], *bdd);
+        if let Some(mut werte) = manager.erfuellende_belegung(unterschied) {
+            for variable in &variablen {
+                werte.entry((*variable).clone()).or_insert(false);
+            }
+            let ergebnisse = funktionen
+                .iter()
+                .map(|(name, funktion)| (String::from(*name), funktion.result(kontext, &werte, false)))
+                .collect();
+            gegenbeispiele.push(Belegung { werte, ergebnisse });
         }
     }
-    return true;
+    Aequivalenz { gegenbeispiele }
 }
 
-fn join_bool_and(a: bool, b: bool)-> bool {
-    a && b
+/// Zählt mit einem BDD die Belegungen der Variabeln der Funktion, die sie erfüllen.
+/// Gibt `None` zurück, wenn die Anzahl nicht in ein `u128` passt.
+pub fn anzahl_modelle(kontext: &FormelKontext, funktion: &AussagenFunktion) -> Option<u128> {
+    let mut manager = BddManager::new();
+    let bdd = manager.aus_funktion(kontext, funktion);
+    manager.anzahl_modelle(bdd)
+}
+
+/// Sucht eine Belegung, die die Funktion erfüllt. Ist die Funktion schon eine 2-KNF, wird sie mit
+/// [`zwei_sat::loese_zwei_sat`] in linearer Zeit gelöst. Sonst wird sie mit der Tseitin-Transformation in KNF
+/// gebracht und an den SAT-Solver gegeben. Das Modell enthält nur die Variabeln der Funktion.
+pub fn ist_erfuellbar(kontext: &FormelKontext, funktion: &AussagenFunktion) -> Option<HashMap<String, bool>> {
+    let modell = match funktion.to_zwei_knf(kontext).and_then(|menge| zwei_sat::loese_zwei_sat(&menge)) {
+        Some(zwei_sat::ZweiSat::Erfuellbar(modell)) => modell,
+        Some(zwei_sat::ZweiSat::Widerspruch(_)) => return None,
+        None => {
+            let mut solver = SatSolver::new();
+            solver.add_klauseln(&funktion.to_knf_tseitin(kontext));
+            solver.loese()?
+        }
+    };
+    Some(
+        funktion
+            .get_keys(kontext)
+            .into_iter()
+            .map(|name| (name.clone(), *modell.get(name).unwrap_or(&false)))
+            .collect(),
+    )
 }
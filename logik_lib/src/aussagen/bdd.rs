@@ -0,0 +1,421 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::aussagen::structures::{AussagenFunktion, FormelKontext};
+
+/// Ein Knoten in einem [`BddManager`]. Zwei Bdds desselben Managers stellen genau dann dieselbe Funktion dar,
+/// wenn sie gleich sind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bdd(usize);
+
+impl Bdd {
+    pub const FALSCH: Bdd = Bdd(0);
+    pub const WAHR: Bdd = Bdd(1);
+
+    pub fn ist_konstant(&self) -> bool {
+        *self == Bdd::FALSCH || *self == Bdd::WAHR
+    }
+}
+
+/// Stufe der beiden Blätter, sie liegen unter allen Variabeln.
+const BLATT: usize = usize::MAX;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Knoten {
+    stufe: usize,
+    niedrig: Bdd,
+    hoch: Bdd,
+}
+
+/// Verwaltet reduzierte geordnete binäre Entscheidungsdiagramme (ROBDDs).
+/// Alle Knoten liegen in der Unique-Table, dadurch gibt es jede Funktion nur einmal und Äquivalenz ist ein Vergleich der [`Bdd`]s.
+/// Die Variabelreihenfolge ist die Reihenfolge, in der die Variabeln dem Manager bekannt werden.
+#[derive(Debug)]
+pub struct BddManager {
+    variablen: Vec<String>,
+    stufen: HashMap<String, usize>,
+    knoten: Vec<Knoten>,
+    eindeutig: HashMap<Knoten, Bdd>,
+    ite_cache: HashMap<(Bdd, Bdd, Bdd), Bdd>,
+}
+
+impl Default for BddManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BddManager {
+    pub fn new() -> BddManager {
+        let blatt = |wert| Knoten {
+            stufe: BLATT,
+            niedrig: wert,
+            hoch: wert,
+        };
+        BddManager {
+            variablen: Vec::new(),
+            stufen: HashMap::new(),
+            knoten: vec![blatt(Bdd::FALSCH), blatt(Bdd::WAHR)],
+            eindeutig: HashMap::new(),
+            ite_cache: HashMap::new(),
+        }
+    }
+
+    /// Die Variabeln in ihrer Reihenfolge im Diagramm.
+    pub fn variablen(&self) -> &[String] {
+        &self.variablen
+    }
+
+    fn stufe(&mut self, name: &str) -> usize {
+        if let Some(stufe) = self.stufen.get(name) {
+            return *stufe;
+        }
+        self.variablen.push(String::from(name));
+        self.stufen.insert(String::from(name), self.variablen.len() - 1);
+        self.variablen.len() - 1
+    }
+
+    /// Der Bdd der Funktion, die nur aus der Variable besteht.
+    pub fn variable(&mut self, name: &str) -> Bdd {
+        let stufe = self.stufe(name);
+        self.knoten(stufe, Bdd::FALSCH, Bdd::WAHR)
+    }
+
+    /// Sucht den Knoten in der Unique-Table oder legt ihn an. Knoten mit gleichen Kindern werden weggelassen.
+    fn knoten(&mut self, stufe: usize, niedrig: Bdd, hoch: Bdd) -> Bdd {
+        if niedrig == hoch {
+            return niedrig;
+        }
+        let knoten = Knoten { stufe, niedrig, hoch };
+        if let Some(bdd) = self.eindeutig.get(&knoten) {
+            return *bdd;
+        }
+        let bdd = Bdd(self.knoten.len());
+        self.knoten.push(knoten);
+        self.eindeutig.insert(knoten, bdd);
+        bdd
+    }
+
+    /// Der Name der Variable, nach der im Knoten verzweigt wird. `None` für die Blätter.
+    pub fn variable_von(&self, bdd: Bdd) -> Option<&String> {
+        self.variablen.get(self.knoten[bdd.0].stufe)
+    }
+
+    /// Der Nachfolger, wenn die Variable des Knotens falsch ist.
+    pub fn niedrig(&self, bdd: Bdd) -> Bdd {
+        self.knoten[bdd.0].niedrig
+    }
+
+    /// Der Nachfolger, wenn die Variable des Knotens wahr ist.
+    pub fn hoch(&self, bdd: Bdd) -> Bdd {
+        self.knoten[bdd.0].hoch
+    }
+
+    /// Die Kofaktoren von `bdd` bezüglich der Variable auf `stufe`.
+    fn kofaktoren(&self, bdd: Bdd, stufe: usize) -> (Bdd, Bdd) {
+        let knoten = self.knoten[bdd.0];
+        if knoten.stufe == stufe {
+            (knoten.niedrig, knoten.hoch)
+        } else {
+            (bdd, bdd)
+        }
+    }
+
+    /// If-then-else: (f ⋀ g) ⋁ (¬f ⋀ h). Alle anderen Verknüpfungen werden darauf zurückgeführt.
+    pub fn ite(&mut self, f: Bdd, g: Bdd, h: Bdd) -> Bdd {
+        if f == Bdd::WAHR || g == h {
+            return g;
+        }
+        if f == Bdd::FALSCH {
+            return h;
+        }
+        if g == Bdd::WAHR && h == Bdd::FALSCH {
+            return f;
+        }
+        if let Some(bdd) = self.ite_cache.get(&(f, g, h)) {
+            return *bdd;
+        }
+        let stufe = self.knoten[f.0]
+            .stufe
+            .min(self.knoten[g.0].stufe)
+            .min(self.knoten[h.0].stufe);
+        let (f0, f1) = self.kofaktoren(f, stufe);
+        let (g0, g1) = self.kofaktoren(g, stufe);
+        let (h0, h1) = self.kofaktoren(h, stufe);
+        let niedrig = self.ite(f0, g0, h0);
+        let hoch = self.ite(f1, g1, h1);
+        let bdd = self.knoten(stufe, niedrig, hoch);
+        self.ite_cache.insert((f, g, h), bdd);
+        bdd
+    }
+
+    pub fn not(&mut self, f: Bdd) -> Bdd {
+        self.ite(f, Bdd::FALSCH, Bdd::WAHR)
+    }
+
+    pub fn and(&mut self, f: Bdd, g: Bdd) -> Bdd {
+        self.ite(f, g, Bdd::FALSCH)
+    }
+
+    pub fn or(&mut self, f: Bdd, g: Bdd) -> Bdd {
+        self.ite(f, Bdd::WAHR, g)
+    }
+
+    pub fn xor(&mut self, f: Bdd, g: Bdd) -> Bdd {
+        let nicht_g = self.not(g);
+        self.ite(f, nicht_g, g)
+    }
+
+    pub fn implikation(&mut self, f: Bdd, g: Bdd) -> Bdd {
+        self.ite(f, g, Bdd::WAHR)
+    }
+
+    pub fn biimplikation(&mut self, f: Bdd, g: Bdd) -> Bdd {
+        let nicht_g = self.not(g);
+        self.ite(f, g, nicht_g)
+    }
+
+    /// Baut den Bdd einer Formel. Verweise auf Funktionen aus dem Kontext werden aufgelöst.
+    pub fn aus_funktion(&mut self, kontext: &FormelKontext, funktion: &AussagenFunktion) -> Bdd {
+        self.aus_funktion_mit_cache(kontext, funktion, &mut HashMap::new())
+    }
+
+    fn aus_funktion_mit_cache(
+        &mut self,
+        kontext: &FormelKontext,
+        funktion: &AussagenFunktion,
+        benannte: &mut HashMap<String, Bdd>,
+    ) -> Bdd {
+        match funktion {
+            AussagenFunktion::VARIABEL(name) => match kontext.funktionen.get(name) {
+                Some(referenz) => {
+                    if let Some(bdd) = benannte.get(name) {
+                        return *bdd;
+                    }
+                    let bdd = self.aus_funktion_mit_cache(kontext, referenz, benannte);
+                    benannte.insert(name.clone(), bdd);
+                    bdd
+                }
+                None => self.variable(name),
+            },
+            AussagenFunktion::TOP() => Bdd::WAHR,
+            AussagenFunktion::BOTTOM() => Bdd::FALSCH,
+            AussagenFunktion::NOT(teil) => {
+                let teil = self.aus_funktion_mit_cache(kontext, teil, benannte);
+                self.not(teil)
+            }
+            AussagenFunktion::AND(funktionen) => {
+                self.verknuepfe(kontext, funktionen, Bdd::WAHR, BddManager::and, benannte)
+            }
+            AussagenFunktion::OR(funktionen) => {
+                self.verknuepfe(kontext, funktionen, Bdd::FALSCH, BddManager::or, benannte)
+            }
+            AussagenFunktion::XOR(funktionen) => {
+                self.verknuepfe(kontext, funktionen, Bdd::FALSCH, BddManager::xor, benannte)
+            }
+            AussagenFunktion::NAND(funktionen) => {
+                let and = self.verknuepfe(kontext, funktionen, Bdd::WAHR, BddManager::and, benannte);
+                self.not(and)
+            }
+            AussagenFunktion::NOR(funktionen) => {
+                let or = self.verknuepfe(kontext, funktionen, Bdd::FALSCH, BddManager::or, benannte);
+                self.not(or)
+            }
+            AussagenFunktion::IMPLIKATION(links, rechts) => {
+                let links = self.aus_funktion_mit_cache(kontext, links, benannte);
+                let rechts = self.aus_funktion_mit_cache(kontext, rechts, benannte);
+                self.implikation(links, rechts)
+            }
+            AussagenFunktion::BIIMPLIKATION(links, rechts) => {
+                let links = self.aus_funktion_mit_cache(kontext, links, benannte);
+                let rechts = self.aus_funktion_mit_cache(kontext, rechts, benannte);
+                self.biimplikation(links, rechts)
+            }
+        }
+    }
+
+    /// Verknüpft die Bdds der Teilformeln der Reihe nach mit `operation`.
+    fn verknuepfe(
+        &mut self,
+        kontext: &FormelKontext,
+        funktionen: &[Box<AussagenFunktion>],
+        start: Bdd,
+        operation: fn(&mut BddManager, Bdd, Bdd) -> Bdd,
+        benannte: &mut HashMap<String, Bdd>,
+    ) -> Bdd {
+        funktionen.iter().fold(start, |ergebnis, teil| {
+            let teil = self.aus_funktion_mit_cache(kontext, teil, benannte);
+            operation(self, ergebnis, teil)
+        })
+    }
+
+    pub fn ist_tautologie(&self, bdd: Bdd) -> bool {
+        bdd == Bdd::WAHR
+    }
+
+    pub fn ist_erfuellbar(&self, bdd: Bdd) -> bool {
+        bdd != Bdd::FALSCH
+    }
+
+    /// Eine erfüllende Belegung der Variabeln auf dem Pfad zu `WAHR`. Variabeln, die nicht vorkommen, fehlen in der Belegung.
+    pub fn erfuellende_belegung(&self, mut bdd: Bdd) -> Option<HashMap<String, bool>> {
+        if bdd == Bdd::FALSCH {
+            return None;
+        }
+        let mut belegung = HashMap::new();
+        while bdd != Bdd::WAHR {
+            let knoten = self.knoten[bdd.0];
+            let wert = knoten.niedrig == Bdd::FALSCH;
+            belegung.insert(self.variablen[knoten.stufe].clone(), wert);
+            bdd = if wert { knoten.hoch } else { knoten.niedrig };
+        }
+        Some(belegung)
+    }
+
+    /// Die Stufe des Knotens, die Blätter liegen auf der Stufe nach der letzten Variable.
+    fn stufe_von(&self, bdd: Bdd) -> usize {
+        if bdd.ist_konstant() {
+            self.variablen.len()
+        } else {
+            self.knoten[bdd.0].stufe
+        }
+    }
+
+    /// Die Anzahl der erfüllenden Belegungen aller Variabeln des Managers.
+    /// Gibt `None` zurück, wenn die Anzahl nicht in ein `u128` passt.
+    pub fn anzahl_modelle(&self, bdd: Bdd) -> Option<u128> {
+        let anzahl = self.anzahl_modelle_ab(bdd, &mut HashMap::new())?;
+        mal_zweierpotenz(anzahl, self.stufe_von(bdd))
+    }
+
+    /// Die Anzahl der Belegungen der Variabeln ab der Stufe von `bdd`, die zu `WAHR` führen.
+    fn anzahl_modelle_ab(&self, bdd: Bdd, cache: &mut HashMap<Bdd, Option<u128>>) -> Option<u128> {
+        if bdd.ist_konstant() {
+            return Some((bdd == Bdd::WAHR) as u128);
+        }
+        if let Some(anzahl) = cache.get(&bdd) {
+            return *anzahl;
+        }
+        let stufe = self.stufe_von(bdd);
+        let mut anzahl = Some(0u128);
+        for kind in [self.niedrig(bdd), self.hoch(bdd)] {
+            let teil = self
+                .anzahl_modelle_ab(kind, cache)
+                .and_then(|teil| mal_zweierpotenz(teil, self.stufe_von(kind) - stufe - 1));
+            anzahl = anzahl.zip(teil).and_then(|(anzahl, teil)| anzahl.checked_add(teil));
+        }
+        cache.insert(bdd, anzahl);
+        anzahl
+    }
+
+    /// Die Anzahl der Knoten, die von `bdd` aus erreichbar sind, die Blätter eingeschlossen.
+    pub fn anzahl_knoten(&self, bdd: Bdd) -> usize {
+        let mut besucht = HashSet::new();
+        let mut offen = vec![bdd];
+        while let Some(bdd) = offen.pop() {
+            if besucht.insert(bdd) && !bdd.ist_konstant() {
+                offen.push(self.niedrig(bdd));
+                offen.push(self.hoch(bdd));
+            }
+        }
+        besucht.len()
+    }
+}
+
+fn mal_zweierpotenz(anzahl: u128, exponent: usize) -> Option<u128> {
+    if anzahl == 0 {
+        return Some(0);
+    }
+    if exponent >= 128 {
+        return None;
+    }
+    anzahl.checked_mul(1 << exponent)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::aussagen::bdd::{Bdd, BddManager};
+    use crate::aussagen::parsing::parse_function;
+    use crate::aussagen::structures::FormelKontext;
+
+    fn bdd(manager: &mut BddManager, kontext: &FormelKontext, formel: &str) -> Bdd {
+        let funktion = parse_function(formel).expect("Fehler bei parse");
+        manager.aus_funktion(kontext, &funktion)
+    }
+
+    #[test]
+    fn kanonisch_test() {
+        let kontext = FormelKontext::new();
+        let mut manager = BddManager::new();
+        assert_eq!(bdd(&mut manager, &kontext, "A & B"), bdd(&mut manager, &kontext, "B & A"));
+        assert_eq!(bdd(&mut manager, &kontext, "A -> B"), bdd(&mut manager, &kontext, "-B -> -A"));
+        assert_eq!(bdd(&mut manager, &kontext, "A !& B"), bdd(&mut manager, &kontext, "-A | -B"));
+        assert_eq!(bdd(&mut manager, &kontext, "A ^ B"), bdd(&mut manager, &kontext, "-(A <-> B)"));
+        assert_eq!(bdd(&mut manager, &kontext, "A | -A"), Bdd::WAHR);
+        assert_eq!(bdd(&mut manager, &kontext, "A !| t"), Bdd::FALSCH);
+        assert_ne!(bdd(&mut manager, &kontext, "A | B"), bdd(&mut manager, &kontext, "A ^ B"));
+    }
+
+    #[test]
+    fn ite_test() {
+        let mut manager = BddManager::new();
+        let a = manager.variable("A");
+        let b = manager.variable("B");
+        let c = manager.variable("C");
+        let ite = manager.ite(a, b, c);
+        assert_eq!(manager.variable_von(ite), Some(&String::from("A")));
+        assert_eq!(manager.niedrig(ite), c);
+        assert_eq!(manager.hoch(ite), b);
+        assert_eq!(manager.anzahl_knoten(ite), 5);
+        assert_eq!(manager.variable_von(Bdd::WAHR), None);
+    }
+
+    #[test]
+    fn viele_variablen_test() {
+        let kontext = FormelKontext::new();
+        let mut manager = BddManager::new();
+        let namen: Vec<String> = (0..40).map(|i| format!("x{}", i)).collect();
+        let vorwaerts = bdd(&mut manager, &kontext, &namen.join(" ^ "));
+        let rueckwaerts: Vec<String> = namen.iter().rev().cloned().collect();
+        let rueckwaerts = bdd(&mut manager, &kontext, &rueckwaerts.join(" ^ "));
+        assert_eq!(vorwaerts, rueckwaerts);
+        assert_eq!(manager.anzahl_knoten(vorwaerts), 2 * 40 - 1 + 2);
+
+        let belegung = manager.erfuellende_belegung(vorwaerts).expect("Nicht erfüllbar");
+        assert_eq!(belegung.values().filter(|wert| **wert).count() % 2, 1);
+        assert!(manager.erfuellende_belegung(Bdd::FALSCH).is_none());
+    }
+
+    #[test]
+    fn anzahl_modelle_test() {
+        let kontext = FormelKontext::new();
+        let mut manager = BddManager::new();
+        let oder = bdd(&mut manager, &kontext, "A | B | C");
+        assert_eq!(manager.anzahl_modelle(oder), Some(7));
+        // Die Anzahl bezieht sich auf alle Variabeln des Managers, hier A, B, C und D.
+        let d = bdd(&mut manager, &kontext, "D");
+        assert_eq!(manager.anzahl_modelle(d), Some(8));
+        assert_eq!(manager.anzahl_modelle(Bdd::WAHR), Some(16));
+        assert_eq!(manager.anzahl_modelle(Bdd::FALSCH), Some(0));
+
+        let mut manager = BddManager::new();
+        let namen: Vec<String> = (0..130).map(|i| format!("x{}", i)).collect();
+        let gross = bdd(&mut manager, &kontext, &namen.join(" | "));
+        assert_eq!(manager.anzahl_modelle(gross), None);
+        let klein = bdd(&mut manager, &kontext, &namen.join(" & "));
+        assert_eq!(manager.anzahl_modelle(klein), Some(1));
+    }
+
+    #[test]
+    fn kontext_test() {
+        let mut kontext = FormelKontext::new();
+        kontext
+            .funktionen
+            .insert(String::from("phi"), *parse_function("A & B").expect("Fehler bei parse"));
+        let mut manager = BddManager::new();
+        let mit_verweis = bdd(&mut manager, &kontext, "phi | C");
+        assert_eq!(mit_verweis, bdd(&mut manager, &kontext, "(A & B) | C"));
+        assert!(!manager.variablen().contains(&String::from("phi")));
+        assert!(manager.ist_erfuellbar(mit_verweis));
+        assert!(!manager.ist_tautologie(mit_verweis));
+    }
+}
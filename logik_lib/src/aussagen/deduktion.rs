@@ -0,0 +1,560 @@
+use std::fmt::{Display, Formatter};
+
+use crate::aussagen::parsing::parse_function;
+use crate::aussagen::structures::AussagenFunktion;
+
+/// Die Regeln des natürlichen Schließens, die der Beweisprüfer kennt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Regel {
+    Praemisse,
+    Annahme,
+    Wiederholung,
+    UndEinfuehrung,
+    UndBeseitigung,
+    OderEinfuehrung,
+    OderBeseitigung,
+    ImplikationEinfuehrung,
+    ImplikationBeseitigung,
+    NegationEinfuehrung,
+    NegationBeseitigung,
+    FalsumBeseitigung,
+    Raa,
+}
+
+impl Display for Regel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Regel::Praemisse => "Prämisse",
+            Regel::Annahme => "Annahme",
+            Regel::Wiederholung => "WH",
+            Regel::UndEinfuehrung => "⋀I",
+            Regel::UndBeseitigung => "⋀E",
+            Regel::OderEinfuehrung => "⋁I",
+            Regel::OderBeseitigung => "⋁E",
+            Regel::ImplikationEinfuehrung => "→I",
+            Regel::ImplikationBeseitigung => "→E",
+            Regel::NegationEinfuehrung => "¬I",
+            Regel::NegationBeseitigung => "¬E",
+            Regel::FalsumBeseitigung => "⊥E",
+            Regel::Raa => "RAA",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl Regel {
+    /// Liest den Namen einer Regel. Für die Junktoren sind die UTF- und die ASCII-Schreibweise erlaubt, z.B. `⋀I` oder `&I`.
+    pub fn parse(name: &str) -> Option<Regel> {
+        match name.to_uppercase().as_str() {
+            "PRÄMISSE" | "PRAEMISSE" | "P" => return Some(Regel::Praemisse),
+            "ANNAHME" | "A" => return Some(Regel::Annahme),
+            "WH" | "R" => return Some(Regel::Wiederholung),
+            "RAA" => return Some(Regel::Raa),
+            _ => {}
+        }
+        let mut zeichen: Vec<char> = name.chars().collect();
+        let art = zeichen.pop()?.to_ascii_uppercase();
+        let junktor: String = zeichen.into_iter().collect();
+        let regel = match (junktor.as_str(), art) {
+            ("⋀" | "∧" | "&", 'I') => Regel::UndEinfuehrung,
+            ("⋀" | "∧" | "&", 'E') => Regel::UndBeseitigung,
+            ("⋁" | "∨" | "|", 'I') => Regel::OderEinfuehrung,
+            ("⋁" | "∨" | "|", 'E') => Regel::OderBeseitigung,
+            ("→" | "->", 'I') => Regel::ImplikationEinfuehrung,
+            ("→" | "->", 'E') => Regel::ImplikationBeseitigung,
+            ("¬" | "-", 'I') => Regel::NegationEinfuehrung,
+            ("¬" | "-", 'E') => Regel::NegationBeseitigung,
+            ("⊥" | "f", 'E') => Regel::FalsumBeseitigung,
+            _ => return None,
+        };
+        Some(regel)
+    }
+}
+
+/// Ein Verweis in der Begründung einer Zeile: eine einzelne Zeile oder ein ganzer Teilbeweis `von-bis`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Referenz {
+    Zeile(usize),
+    Teilbeweis(usize, usize),
+}
+
+impl Display for Referenz {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Referenz::Zeile(zeile) => write!(f, "{}", zeile),
+            Referenz::Teilbeweis(von, bis) => write!(f, "{}-{}", von, bis),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BeweisZeile {
+    pub nummer: usize,
+    /// Wie viele Teilbeweise die Zeile umgeben, also die Anzahl der `|` vor der Formel.
+    pub tiefe: usize,
+    pub formel: AussagenFunktion,
+    pub regel: Regel,
+    pub referenzen: Vec<Referenz>,
+}
+
+/// Ein Fehler im Beweis mit der Zeile und, wenn sie bekannt ist, der Regel.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BeweisFehler {
+    pub zeile: usize,
+    pub regel: Option<Regel>,
+    pub nachricht: String,
+}
+
+impl Display for BeweisFehler {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.regel {
+            Some(regel) => write!(f, "Zeile {} ({}): {}", self.zeile, regel, self.nachricht),
+            None => write!(f, "Zeile {}: {}", self.zeile, self.nachricht),
+        }
+    }
+}
+
+/// Ein Beweis im Fitch-Stil. Jede Zeile hat die Form `<NR> <|...> <FORMEL> : <REGEL> <REFERENZEN>`,
+/// z.B. `3 | A & B : &I 1, 2` oder `5 A -> B : ->I 2-4`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Beweis {
+    pub zeilen: Vec<BeweisZeile>,
+}
+
+fn parse_zeile(index: usize, text: &str) -> Result<BeweisZeile, BeweisFehler> {
+    let fehler = |nachricht: String| BeweisFehler {
+        zeile: index + 1,
+        regel: None,
+        nachricht,
+    };
+    let (links, rechts) = text
+        .split_once(':')
+        .ok_or_else(|| fehler(String::from("Die Begründung muss mit : von der Formel getrennt werden")))?;
+
+    let links = links.trim_start();
+    let ende = links.find(char::is_whitespace).unwrap_or(links.len());
+    let nummer = links[..ende]
+        .trim_end_matches('.')
+        .parse::<usize>()
+        .map_err(|_| fehler(format!("{} ist keine Zeilennummer", &links[..ende])))?;
+    if nummer != index + 1 {
+        return Err(fehler(format!("Die Zeile hat die Nummer {}", nummer)));
+    }
+    let mut formel = links[ende..].trim_start();
+    let mut tiefe = 0;
+    while let Some(rest) = formel.strip_prefix('|') {
+        tiefe += 1;
+        formel = rest.trim_start();
+    }
+    let formel = parse_function(formel).map_err(|parse_error| fehler(format!("{}", parse_error)))?;
+
+    let rechts = rechts.replace(',', " ");
+    let mut woerter = rechts.split_whitespace();
+    let name = woerter.next().ok_or_else(|| fehler(String::from("Die Regel fehlt")))?;
+    let regel = Regel::parse(name).ok_or_else(|| fehler(format!("Unbekannte Regel {}", name)))?;
+    let referenzen = woerter
+        .map(|wort| {
+            let zahl = |text: &str| {
+                text.parse::<usize>()
+                    .map_err(|_| fehler(format!("{} ist kein Verweis auf eine Zeile", wort)))
+            };
+            match wort.split_once('-') {
+                Some((von, bis)) => Ok(Referenz::Teilbeweis(zahl(von)?, zahl(bis)?)),
+                None => Ok(Referenz::Zeile(zahl(wort)?)),
+            }
+        })
+        .collect::<Result<Vec<Referenz>, BeweisFehler>>()?;
+
+    Ok(BeweisZeile {
+        nummer,
+        tiefe,
+        formel: *formel,
+        regel,
+        referenzen,
+    })
+}
+
+impl Beweis {
+    /// Liest den Beweis zeilenweise, leere Zeilen werden übersprungen.
+    pub fn parse(text: &str) -> Result<Beweis, Vec<BeweisFehler>> {
+        let mut zeilen = Vec::new();
+        let mut fehler = Vec::new();
+        for zeile in text.lines().filter(|zeile| !zeile.trim().is_empty()) {
+            match parse_zeile(zeilen.len() + fehler.len(), zeile) {
+                Ok(zeile) => zeilen.push(zeile),
+                Err(f) => fehler.push(f),
+            }
+        }
+        if fehler.is_empty() {
+            Ok(Beweis { zeilen })
+        } else {
+            Err(fehler)
+        }
+    }
+
+    /// Die Prämissen und die letzte Zeile als Sequenz `Γ ⊢ φ`.
+    pub fn sequenz(&self) -> String {
+        let praemissen: Vec<String> = self
+            .zeilen
+            .iter()
+            .filter(|zeile| zeile.regel == Regel::Praemisse)
+            .map(|zeile| zeile.formel.to_utf_string())
+            .collect();
+        match self.zeilen.last() {
+            Some(letzte) => format!("{} ⊢ {}", praemissen.join(", "), letzte.formel).trim_start().to_string(),
+            None => String::new(),
+        }
+    }
+
+    /// Prüft jeden Schritt des Beweises und gibt alle Fehler zurück. Ist die Liste leer, ist der Beweis korrekt.
+    pub fn pruefe(&self) -> Vec<BeweisFehler> {
+        let pfade = match self.pfade() {
+            Ok(pfade) => pfade,
+            Err(fehler) => return vec![fehler],
+        };
+        let mut fehler = Vec::new();
+        for (index, zeile) in self.zeilen.iter().enumerate() {
+            let pruefer = Pruefer {
+                beweis: self,
+                pfade: &pfade,
+                index,
+            };
+            if let Err(nachricht) = pruefer.pruefe() {
+                fehler.push(BeweisFehler {
+                    zeile: zeile.nummer,
+                    regel: Some(zeile.regel),
+                    nachricht,
+                });
+            }
+        }
+        if let Some(letzte) = self.zeilen.last() {
+            if letzte.tiefe > 0 {
+                fehler.push(BeweisFehler {
+                    zeile: letzte.nummer,
+                    regel: Some(letzte.regel),
+                    nachricht: String::from("Der Beweis endet in einem offenen Teilbeweis"),
+                });
+            }
+        }
+        fehler
+    }
+
+    /// Für jede Zeile die Anfangszeilen der Teilbeweise, in denen sie steht, von außen nach innen.
+    fn pfade(&self) -> Result<Vec<Vec<usize>>, BeweisFehler> {
+        let mut pfade = Vec::new();
+        let mut offen: Vec<usize> = Vec::new();
+        for zeile in &self.zeilen {
+            let fehler = |nachricht: &str| BeweisFehler {
+                zeile: zeile.nummer,
+                regel: Some(zeile.regel),
+                nachricht: String::from(nachricht),
+            };
+            if zeile.regel == Regel::Annahme {
+                if zeile.tiefe == 0 || zeile.tiefe > offen.len() + 1 {
+                    return Err(fehler("Eine Annahme muss genau einen neuen Teilbeweis öffnen"));
+                }
+                offen.truncate(zeile.tiefe - 1);
+                offen.push(zeile.nummer);
+            } else {
+                if zeile.tiefe > offen.len() {
+                    return Err(fehler("Ein Teilbeweis muss mit einer Annahme beginnen"));
+                }
+                offen.truncate(zeile.tiefe);
+            }
+            pfade.push(offen.clone());
+        }
+        Ok(pfade)
+    }
+}
+
+/// Prüft eine einzelne Zeile. Die Fehler sind Nachrichten, Zeile und Regel ergänzt [`Beweis::pruefe`].
+struct Pruefer<'a> {
+    beweis: &'a Beweis,
+    pfade: &'a [Vec<usize>],
+    index: usize,
+}
+
+impl<'a> Pruefer<'a> {
+    fn zeile(&self) -> &'a BeweisZeile {
+        &self.beweis.zeilen[self.index]
+    }
+
+    /// Die Formel einer früheren Zeile, die in keinem bereits geschlossenen Teilbeweis steht.
+    fn formel(&self, nummer: usize) -> Result<&'a AussagenFunktion, String> {
+        if nummer == 0 || nummer > self.index {
+            return Err(format!("Zeile {} steht nicht vor dieser Zeile", nummer));
+        }
+        if !self.pfade[self.index].starts_with(&self.pfade[nummer - 1]) {
+            return Err(format!("Zeile {} steht in einem abgeschlossenen Teilbeweis", nummer));
+        }
+        Ok(&self.beweis.zeilen[nummer - 1].formel)
+    }
+
+    /// Die Annahme und die letzte Zeile eines Teilbeweises, der direkt vor dieser Zeile abgeschlossen wurde
+    /// oder in derselben Umgebung wie diese Zeile steht.
+    fn teilbeweis(&self, von: usize, bis: usize) -> Result<(&'a AussagenFunktion, &'a AussagenFunktion), String> {
+        let ungueltig = || format!("{}-{} ist kein abgeschlossener Teilbeweis", von, bis);
+        if von == 0 || bis < von || bis > self.index {
+            return Err(ungueltig());
+        }
+        let annahme = &self.beweis.zeilen[von - 1];
+        let mut pfad = self.pfade[self.index].clone();
+        pfad.push(von);
+        if annahme.regel != Regel::Annahme || self.pfade[von - 1] != pfad || self.pfade[bis - 1] != pfad {
+            return Err(ungueltig());
+        }
+        if (bis..self.index).any(|danach| self.pfade[danach].starts_with(&pfad)) {
+            return Err(format!("{}-{} endet nicht mit Zeile {}", von, bis, bis));
+        }
+        Ok((&annahme.formel, &self.beweis.zeilen[bis - 1].formel))
+    }
+
+    fn zeilen(&self, anzahl: usize) -> Result<Vec<&'a AussagenFunktion>, String> {
+        let referenzen = &self.zeile().referenzen;
+        if referenzen.len() != anzahl {
+            return Err(format!("Erwartet {} Verweise auf Zeilen", anzahl));
+        }
+        referenzen
+            .iter()
+            .map(|referenz| match referenz {
+                Referenz::Zeile(nummer) => self.formel(*nummer),
+                Referenz::Teilbeweis(_, _) => Err(format!("{} muss eine einzelne Zeile sein", referenz)),
+            })
+            .collect()
+    }
+
+    fn einzelner_teilbeweis(&self) -> Result<(&'a AussagenFunktion, &'a AussagenFunktion), String> {
+        match self.zeile().referenzen.as_slice() {
+            [Referenz::Teilbeweis(von, bis)] => self.teilbeweis(*von, *bis),
+            _ => Err(String::from("Erwartet einen Verweis auf einen Teilbeweis von-bis")),
+        }
+    }
+
+    fn pruefe(&self) -> Result<(), String> {
+        let zeile = self.zeile();
+        let formel = &zeile.formel;
+        let falsum = AussagenFunktion::BOTTOM();
+        let erwarte = |bedingung: bool, nachricht: &str| {
+            if bedingung {
+                Ok(())
+            } else {
+                Err(String::from(nachricht))
+            }
+        };
+        match zeile.regel {
+            Regel::Praemisse => erwarte(
+                self.beweis.zeilen[..self.index]
+                    .iter()
+                    .all(|vorher| vorher.regel == Regel::Praemisse)
+                    && zeile.tiefe == 0,
+                "Prämissen müssen am Anfang des Beweises stehen",
+            ),
+            Regel::Annahme => erwarte(zeile.referenzen.is_empty(), "Eine Annahme hat keine Verweise"),
+            Regel::Wiederholung => {
+                let quelle = self.zeilen(1)?[0];
+                erwarte(quelle == formel, "Die Formel muss genau der Formel der Zeile entsprechen")
+            }
+            Regel::UndEinfuehrung => match formel {
+                AussagenFunktion::AND(teile) => {
+                    let quellen = self.zeilen(teile.len())?;
+                    erwarte(
+                        teile.iter().all(|teil| quellen.contains(&&**teil))
+                            && quellen.iter().all(|quelle| teile.iter().any(|teil| **teil == **quelle)),
+                        "Die Konjunktion muss aus den Formeln der Zeilen bestehen",
+                    )
+                }
+                _ => Err(String::from("Die Formel muss eine Konjunktion sein")),
+            },
+            Regel::UndBeseitigung => match self.zeilen(1)?[0] {
+                AussagenFunktion::AND(teile) => erwarte(
+                    teile.iter().any(|teil| **teil == *formel),
+                    "Die Formel muss ein Teil der Konjunktion sein",
+                ),
+                _ => Err(String::from("Die Zeile muss eine Konjunktion sein")),
+            },
+            Regel::OderEinfuehrung => {
+                let quelle = self.zeilen(1)?[0];
+                match formel {
+                    AussagenFunktion::OR(teile) => erwarte(
+                        teile.iter().any(|teil| **teil == *quelle),
+                        "Die Formel der Zeile muss ein Teil der Disjunktion sein",
+                    ),
+                    _ => Err(String::from("Die Formel muss eine Disjunktion sein")),
+                }
+            }
+            Regel::OderBeseitigung => self.pruefe_oder_beseitigung(),
+            Regel::ImplikationEinfuehrung => {
+                let (annahme, ende) = self.einzelner_teilbeweis()?;
+                match formel {
+                    AussagenFunktion::IMPLIKATION(links, rechts) => erwarte(
+                        **links == *annahme && **rechts == *ende,
+                        "Die Implikation muss von der Annahme zur letzten Zeile des Teilbeweises führen",
+                    ),
+                    _ => Err(String::from("Die Formel muss eine Implikation sein")),
+                }
+            }
+            Regel::ImplikationBeseitigung => {
+                let quellen = self.zeilen(2)?;
+                let passt = |implikation: &AussagenFunktion, praemisse: &AussagenFunktion| match implikation {
+                    AussagenFunktion::IMPLIKATION(links, rechts) => **links == *praemisse && **rechts == *formel,
+                    _ => false,
+                };
+                erwarte(
+                    passt(quellen[0], quellen[1]) || passt(quellen[1], quellen[0]),
+                    "Die Zeilen müssen φ → ψ und φ sein, die Formel ψ",
+                )
+            }
+            Regel::NegationEinfuehrung => {
+                let (annahme, ende) = self.einzelner_teilbeweis()?;
+                erwarte(*ende == falsum, "Der Teilbeweis muss mit ⊥ enden")?;
+                erwarte(
+                    *formel == AussagenFunktion::NOT(Box::new(annahme.clone())),
+                    "Die Formel muss die Negation der Annahme sein",
+                )
+            }
+            Regel::NegationBeseitigung => {
+                let quellen = self.zeilen(2)?;
+                let negiert = |negation: &AussagenFunktion, positiv: &AussagenFunktion| match negation {
+                    AussagenFunktion::NOT(inner) => **inner == *positiv,
+                    _ => false,
+                };
+                erwarte(
+                    negiert(quellen[0], quellen[1]) || negiert(quellen[1], quellen[0]),
+                    "Die Zeilen müssen φ und ¬φ sein",
+                )?;
+                erwarte(*formel == falsum, "Die Formel muss ⊥ sein")
+            }
+            Regel::FalsumBeseitigung => {
+                let quelle = self.zeilen(1)?[0];
+                erwarte(*quelle == falsum, "Die Zeile muss ⊥ sein")
+            }
+            Regel::Raa => {
+                let (annahme, ende) = self.einzelner_teilbeweis()?;
+                erwarte(*ende == falsum, "Der Teilbeweis muss mit ⊥ enden")?;
+                erwarte(
+                    *annahme == AussagenFunktion::NOT(Box::new(formel.clone())),
+                    "Die Annahme muss die Negation der Formel sein",
+                )
+            }
+        }
+    }
+
+    /// ⋁E: eine Zeile φ₁ ⋁ ... ⋁ φₙ und für jedes φᵢ ein Teilbeweis, der mit der Formel endet.
+    fn pruefe_oder_beseitigung(&self) -> Result<(), String> {
+        let zeile = self.zeile();
+        let mut disjunktion = None;
+        let mut teilbeweise = Vec::new();
+        for referenz in &zeile.referenzen {
+            match referenz {
+                Referenz::Zeile(nummer) if disjunktion.is_none() => disjunktion = Some(self.formel(*nummer)?),
+                Referenz::Zeile(_) => return Err(String::from("Erwartet nur einen Verweis auf eine Disjunktion")),
+                Referenz::Teilbeweis(von, bis) => teilbeweise.push(self.teilbeweis(*von, *bis)?),
+            }
+        }
+        let teile = match disjunktion {
+            Some(AussagenFunktion::OR(teile)) => teile,
+            Some(_) => return Err(String::from("Die Zeile muss eine Disjunktion sein")),
+            None => return Err(String::from("Erwartet einen Verweis auf eine Disjunktion")),
+        };
+        if teilbeweise.len() != teile.len() {
+            return Err(format!("Erwartet {} Teilbeweise, einen für jeden Fall", teile.len()));
+        }
+        for teil in teile {
+            if !teilbeweise.iter().any(|(annahme, _)| **annahme == **teil) {
+                return Err(format!("Es fehlt ein Teilbeweis mit der Annahme {}", teil));
+            }
+        }
+        if teilbeweise.iter().any(|(_, ende)| **ende != zeile.formel) {
+            return Err(String::from("Jeder Teilbeweis muss mit der Formel enden"));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::aussagen::deduktion::{Beweis, Regel};
+
+    fn fehler(text: &str) -> Vec<String> {
+        let beweis = Beweis::parse(text).expect("Fehler beim Lesen");
+        beweis.pruefe().iter().map(|fehler| fehler.to_string()).collect()
+    }
+
+    #[test]
+    fn regel_test() {
+        assert_eq!(Regel::parse("∧I"), Some(Regel::UndEinfuehrung));
+        assert_eq!(Regel::parse("&e"), Some(Regel::UndBeseitigung));
+        assert_eq!(Regel::parse("->I"), Some(Regel::ImplikationEinfuehrung));
+        assert_eq!(Regel::parse("⊥E"), Some(Regel::FalsumBeseitigung));
+        assert_eq!(Regel::parse("raa"), Some(Regel::Raa));
+        assert_eq!(Regel::parse("->X"), None);
+    }
+
+    #[test]
+    fn korrekt_test() {
+        let beweis = "
+            1 A & B : Prämisse
+            2 A -> C : Prämisse
+            3 A : &E 1
+            4 C : ->E 2, 3
+            5 | -C : Annahme
+            6 | f : -E 4, 5
+            7 --C : -I 5-6
+            8 B | D : |I 1
+        ";
+        assert_eq!(fehler(beweis), vec!["Zeile 8 (⋁I): Die Formel der Zeile muss ein Teil der Disjunktion sein"]);
+
+        let beweis = "
+            1 A | B : P
+            2 | A : Annahme
+            3 | B | A : |I 2
+            4 | B : Annahme
+            5 | B | A : |I 4
+            6 B | A : |E 1, 2-3, 4-5
+            7 | -(B | A) : Annahme
+            8 | f : -E 6, 7
+            9 B | A : RAA 7-8
+            10 | C : A
+            11 | | A : A
+            12 | | C : WH 10
+            13 | A -> C : ->I 11-12
+            14 C -> (A -> C) : ->I 10-13
+        ";
+        assert!(fehler(beweis).is_empty(), "{:?}", fehler(beweis));
+        let beweis = Beweis::parse(beweis).unwrap();
+        assert_eq!(beweis.sequenz(), "(A ⋁ B) ⊢ (C → (A → C))");
+    }
+
+    #[test]
+    fn fehler_test() {
+        let beweis = "
+            1 A : Prämisse
+            2 | B : Annahme
+            3 | A & B : &I 1, 2
+            4 A & B : WH 3
+            5 B -> A : ->I 2-2
+            6 A & A : &I 1, 4
+        ";
+        assert_eq!(
+            fehler(beweis),
+            vec![
+                "Zeile 4 (WH): Zeile 3 steht in einem abgeschlossenen Teilbeweis",
+                "Zeile 5 (→I): 2-2 endet nicht mit Zeile 2",
+                "Zeile 6 (⋀I): Die Konjunktion muss aus den Formeln der Zeilen bestehen",
+            ]
+        );
+
+        let beweis = "
+            1 A : Prämisse
+            2 | B : Annahme
+            3 | A : WH 1
+        ";
+        assert_eq!(fehler(beweis), vec!["Zeile 3 (WH): Der Beweis endet in einem offenen Teilbeweis"]);
+
+        let fehler = Beweis::parse("1 A : Prämisse\n3 B : WH 1\n").unwrap_err();
+        assert_eq!(fehler[0].to_string(), "Zeile 2: Die Zeile hat die Nummer 3");
+        let fehler = Beweis::parse("1 A & : Prämisse\n2 A : XY").unwrap_err();
+        assert_eq!(fehler.len(), 2);
+        assert_eq!(fehler[1].to_string(), "Zeile 2: Unbekannte Regel XY");
+    }
+}
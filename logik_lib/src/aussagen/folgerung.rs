@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+use crate::aussagen::ist_erfuellbar;
+use crate::aussagen::structures::{werte_to_string, AussagenFunktion, FormelKontext};
+
+/// Ob die Konklusion aus den Prämissen folgt (Γ ⊨ ψ). Sonst ein Modell der Prämissen, in dem die Konklusion falsch ist.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Folgerung {
+    Gueltig,
+    Gegenmodell(HashMap<String, bool>),
+}
+
+impl Folgerung {
+    pub fn ist_gueltig(&self) -> bool {
+        *self == Folgerung::Gueltig
+    }
+}
+
+impl Display for Folgerung {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Folgerung::Gueltig => write!(f, "Gültig"),
+            Folgerung::Gegenmodell(werte) => write!(f, "Ungültig\nGegenmodell: {}", werte_to_string(werte)),
+        }
+    }
+}
+
+/// Prüft Γ ⊨ ψ, indem der SAT-Solver ein Modell von Γ ⋃ {¬ψ} sucht. Ohne Prämissen wird geprüft, ob ψ eine Tautologie ist.
+pub fn folgert(
+    kontext: &FormelKontext,
+    praemissen: &[&AussagenFunktion],
+    konklusion: &AussagenFunktion,
+) -> Folgerung {
+    let mut teile: Vec<Box<AussagenFunktion>> = praemissen.iter().map(|praemisse| Box::new((*praemisse).clone())).collect();
+    teile.push(Box::new(AussagenFunktion::NOT(Box::new(konklusion.clone()))));
+    match ist_erfuellbar(kontext, &AussagenFunktion::AND(teile)) {
+        None => Folgerung::Gueltig,
+        Some(werte) => Folgerung::Gegenmodell(werte),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::aussagen::folgerung::{folgert, Folgerung};
+    use crate::aussagen::parsing::parse_function;
+    use crate::aussagen::structures::FormelKontext;
+
+    #[test]
+    fn modus_ponens_test() {
+        let kontext = FormelKontext::new();
+        let implikation = parse_function("A -> B").expect("Fehler bei parse");
+        let a = parse_function("A").expect("Fehler bei parse");
+        let b = parse_function("B").expect("Fehler bei parse");
+        assert!(folgert(&kontext, &[&implikation, &a], &b).ist_gueltig());
+
+        // Bejahung des Konsequens ist ein Fehlschluss.
+        match folgert(&kontext, &[&implikation, &b], &a) {
+            Folgerung::Gegenmodell(werte) => {
+                assert!(!werte["A"]);
+                assert!(werte["B"]);
+            }
+            Folgerung::Gueltig => panic!("B, A → B ⊭ A"),
+        }
+    }
+
+    #[test]
+    fn ohne_praemissen_test() {
+        let kontext = FormelKontext::new();
+        let tautologie = parse_function("A | -A").expect("Fehler bei parse");
+        assert!(folgert(&kontext, &[], &tautologie).ist_gueltig());
+        let widerspruch = parse_function("A & -A").expect("Fehler bei parse");
+        let c = parse_function("C").expect("Fehler bei parse");
+        assert!(folgert(&kontext, &[&widerspruch], &c).ist_gueltig());
+        assert!(!folgert(&kontext, &[], &c).ist_gueltig());
+    }
+}
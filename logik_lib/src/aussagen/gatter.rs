@@ -0,0 +1,531 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+use crate::aussagen::structures::{AussagenFunktion, FormelKontext};
+
+/// Der einzige Junktor, der nach der Umformung noch vorkommt. Beide sind für sich allein funktional vollständig.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Gattertyp {
+    Nand,
+    Nor,
+}
+
+impl Gattertyp {
+    fn symbol(&self) -> &'static str {
+        match self {
+            Gattertyp::Nand => "↑",
+            Gattertyp::Nor => "↓",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum GatterFehler {
+    /// Die Formel enthält einen anderen Junktor als das Gatter.
+    FalscherJunktor(String, Gattertyp),
+}
+
+impl Display for GatterFehler {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GatterFehler::FalscherJunktor(formel, typ) => {
+                write!(f, "Die Formel {} verwendet nicht nur {}", formel, typ.symbol())
+            }
+        }
+    }
+}
+
+impl AussagenFunktion {
+    /// Formt die Funktion in eine äquivalente Formel um, die nur ↑ verwendet.
+    /// Verweise auf Funktionen aus dem Kontext werden aufgelöst, ⊤ und ⊥ bleiben als konstante Eingänge erhalten.
+    /// Die Formel kann exponentiell größer sein als die [`Schaltung`], aus der sie ausgeschrieben wird.
+    pub fn to_nand(&self, kontext: &FormelKontext) -> AussagenFunktion {
+        Schaltung::aus_funktion(kontext, self, Gattertyp::Nand).to_funktion()
+    }
+
+    /// Formt die Funktion in eine äquivalente Formel um, die nur ↓ verwendet. Siehe [`Self::to_nand`].
+    pub fn to_nor(&self, kontext: &FormelKontext) -> AussagenFunktion {
+        Schaltung::aus_funktion(kontext, self, Gattertyp::Nor).to_funktion()
+    }
+}
+
+/// Legt Gatter an und gibt für gleiche Eingänge immer dasselbe Gatter zurück. Da beide Gatter kommutativ sind,
+/// werden die Eingänge dafür sortiert verglichen, sodass auch `a ↑ b` und `b ↑ a` dasselbe Gatter sind.
+struct Aufbau {
+    schaltung: Schaltung,
+    bekannte: HashMap<Vec<Signal>, usize>,
+}
+
+impl Aufbau {
+    fn new(typ: Gattertyp) -> Aufbau {
+        Aufbau {
+            schaltung: Schaltung {
+                typ,
+                gatter: Vec::new(),
+                ausgang: Signal::Konstante(false),
+            },
+            bekannte: HashMap::new(),
+        }
+    }
+
+    fn gatter(&mut self, eingaenge: Vec<Signal>) -> Signal {
+        let mut schluessel = eingaenge.clone();
+        schluessel.sort();
+        let gatter = &mut self.schaltung.gatter;
+        let index = *self.bekannte.entry(schluessel).or_insert_with(|| {
+            gatter.push(eingaenge);
+            gatter.len() - 1
+        });
+        Signal::Gatter(index)
+    }
+
+    /// Setzt den Ausgang und entfernt die Gatter, die er nicht braucht. Die Reihenfolge der übrigen bleibt erhalten.
+    fn fertig(mut self, ausgang: Signal) -> Schaltung {
+        let gatter = &self.schaltung.gatter;
+        let mut gebraucht = vec![false; gatter.len()];
+        if let Signal::Gatter(index) = ausgang {
+            gebraucht[index] = true;
+        }
+        for index in (0..gatter.len()).rev() {
+            if gebraucht[index] {
+                for eingang in &gatter[index] {
+                    if let Signal::Gatter(eingang) = eingang {
+                        gebraucht[*eingang] = true;
+                    }
+                }
+            }
+        }
+        let mut neuer_index = vec![0; gatter.len()];
+        let mut anzahl = 0;
+        for index in 0..gatter.len() {
+            neuer_index[index] = anzahl;
+            anzahl += gebraucht[index] as usize;
+        }
+        let umbenennen = |signal: Signal| match signal {
+            Signal::Gatter(index) => Signal::Gatter(neuer_index[index]),
+            signal => signal,
+        };
+        self.schaltung.gatter = std::mem::take(&mut self.schaltung.gatter)
+            .into_iter()
+            .zip(gebraucht)
+            .filter(|(_, gebraucht)| *gebraucht)
+            .map(|(eingaenge, _)| eingaenge.into_iter().map(umbenennen).collect())
+            .collect();
+        self.schaltung.ausgang = umbenennen(ausgang);
+        self.schaltung
+    }
+
+    /// Übernimmt eine Formel, die nur aus Gattern des Typs besteht.
+    fn signal(&mut self, funktion: &AussagenFunktion) -> Result<Signal, GatterFehler> {
+        let eingaenge = match (funktion, self.schaltung.typ) {
+            (AussagenFunktion::VARIABEL(name), _) => return Ok(Signal::Eingang(name.clone())),
+            (AussagenFunktion::TOP(), _) => return Ok(Signal::Konstante(true)),
+            (AussagenFunktion::BOTTOM(), _) => return Ok(Signal::Konstante(false)),
+            (AussagenFunktion::NAND(eingaenge), Gattertyp::Nand) | (AussagenFunktion::NOR(eingaenge), Gattertyp::Nor) => {
+                eingaenge
+            }
+            (_, typ) => return Err(GatterFehler::FalscherJunktor(funktion.to_utf_string(), typ)),
+        };
+        let signale = eingaenge
+            .iter()
+            .map(|eingang| self.signal(eingang))
+            .collect::<Result<Vec<Signal>, GatterFehler>>()?;
+        Ok(self.gatter(signale))
+    }
+}
+
+/// Formt eine beliebige Formel direkt in Gatter um. Jeder Teilterm wird nur einmal umgeformt und bekommt sofort
+/// sein gemeinsames Gatter, so bleibt die Schaltung linear in der Größe der Formel.
+struct Umformung<'a> {
+    kontext: &'a FormelKontext,
+    aufbau: Aufbau,
+    /// Die schon umgeformten Verweise auf Funktionen aus dem Kontext.
+    referenzen: HashMap<String, Signal>,
+}
+
+impl Umformung<'_> {
+    fn typ(&self) -> Gattertyp {
+        self.aufbau.schaltung.typ
+    }
+
+    fn gatter(&mut self, eingaenge: Vec<Signal>) -> Signal {
+        self.aufbau.gatter(eingaenge)
+    }
+
+    /// ¬x = x ↑ x = x ↓ x. Eine doppelte Negation wird dabei gleich entfernt.
+    fn nicht(&mut self, signal: Signal) -> Signal {
+        match &signal {
+            Signal::Konstante(wert) => Signal::Konstante(!wert),
+            Signal::Gatter(index) => {
+                let eingaenge = &self.aufbau.schaltung.gatter[*index];
+                if eingaenge.len() == 2 && eingaenge[0] == eingaenge[1] {
+                    eingaenge[0].clone()
+                } else {
+                    self.gatter(vec![signal.clone(), signal])
+                }
+            }
+            Signal::Eingang(_) => self.gatter(vec![signal.clone(), signal]),
+        }
+    }
+
+    fn alle_nicht(&mut self, signale: Vec<Signal>) -> Vec<Signal> {
+        signale.into_iter().map(|signal| self.nicht(signal)).collect()
+    }
+
+    fn und(&mut self, mut signale: Vec<Signal>) -> Signal {
+        match signale.len() {
+            0 => Signal::Konstante(true),
+            1 => signale.remove(0),
+            _ => match self.typ() {
+                Gattertyp::Nand => {
+                    let gatter = self.gatter(signale);
+                    self.nicht(gatter)
+                }
+                Gattertyp::Nor => {
+                    let negiert = self.alle_nicht(signale);
+                    self.gatter(negiert)
+                }
+            },
+        }
+    }
+
+    fn oder(&mut self, mut signale: Vec<Signal>) -> Signal {
+        match signale.len() {
+            0 => Signal::Konstante(false),
+            1 => signale.remove(0),
+            _ => match self.typ() {
+                Gattertyp::Nand => {
+                    let negiert = self.alle_nicht(signale);
+                    self.gatter(negiert)
+                }
+                Gattertyp::Nor => {
+                    let gatter = self.gatter(signale);
+                    self.nicht(gatter)
+                }
+            },
+        }
+    }
+
+    /// Die Schaltung aus vier Gattern mit t = a ∘ b und (a ∘ t) ∘ (b ∘ t). Mit ↑ ist das a ⊕ b, mit ↓ a ↔ b.
+    fn vier_gatter(&mut self, a: Signal, b: Signal) -> Signal {
+        let t = self.gatter(vec![a.clone(), b.clone()]);
+        let links = self.gatter(vec![a, t.clone()]);
+        let rechts = self.gatter(vec![b, t]);
+        self.gatter(vec![links, rechts])
+    }
+
+    fn xor(&mut self, a: Signal, b: Signal) -> Signal {
+        let gatter = self.vier_gatter(a, b);
+        match self.typ() {
+            Gattertyp::Nand => gatter,
+            Gattertyp::Nor => self.nicht(gatter),
+        }
+    }
+
+    /// Verknüpft die Signale als ausgeglichenen Baum von ⊕. Jedes Signal geht dreimal in ein ⊕ ein, als Kette
+    /// würde die ausgeschriebene Formel daher mit 3^n wachsen, als Baum nur polynomiell.
+    fn xor_baum(&mut self, mut signale: Vec<Signal>) -> Signal {
+        match signale.len() {
+            0 => Signal::Konstante(false),
+            1 => signale.remove(0),
+            anzahl => {
+                let rechts = signale.split_off(anzahl / 2);
+                let links = self.xor_baum(signale);
+                let rechts = self.xor_baum(rechts);
+                self.xor(links, rechts)
+            }
+        }
+    }
+
+    fn biimplikation(&mut self, a: Signal, b: Signal) -> Signal {
+        let gatter = self.vier_gatter(a, b);
+        match self.typ() {
+            Gattertyp::Nand => self.nicht(gatter),
+            Gattertyp::Nor => gatter,
+        }
+    }
+
+    fn forme_alle(&mut self, funktionen: &[Box<AussagenFunktion>]) -> Vec<Signal> {
+        funktionen.iter().map(|funktion| self.forme(funktion)).collect()
+    }
+
+    fn forme(&mut self, funktion: &AussagenFunktion) -> Signal {
+        match funktion {
+            AussagenFunktion::VARIABEL(name) => match self.kontext.funktionen.get(name) {
+                Some(referenz) => {
+                    if let Some(signal) = self.referenzen.get(name) {
+                        return signal.clone();
+                    }
+                    let signal = self.forme(referenz);
+                    self.referenzen.insert(name.clone(), signal.clone());
+                    signal
+                }
+                None => Signal::Eingang(name.clone()),
+            },
+            AussagenFunktion::TOP() => Signal::Konstante(true),
+            AussagenFunktion::BOTTOM() => Signal::Konstante(false),
+            AussagenFunktion::NOT(inner) => {
+                let signal = self.forme(inner);
+                self.nicht(signal)
+            }
+            AussagenFunktion::AND(funktionen) => {
+                let signale = self.forme_alle(funktionen);
+                self.und(signale)
+            }
+            AussagenFunktion::OR(funktionen) => {
+                let signale = self.forme_alle(funktionen);
+                self.oder(signale)
+            }
+            AussagenFunktion::NAND(funktionen) => {
+                let signale = self.forme_alle(funktionen);
+                let und = self.und(signale);
+                self.nicht(und)
+            }
+            AussagenFunktion::NOR(funktionen) => {
+                let signale = self.forme_alle(funktionen);
+                let oder = self.oder(signale);
+                self.nicht(oder)
+            }
+            AussagenFunktion::XOR(funktionen) => {
+                let mut operanden = Vec::new();
+                xor_operanden(funktionen, &mut operanden);
+                let signale = operanden.into_iter().map(|funktion| self.forme(funktion)).collect();
+                self.xor_baum(signale)
+            }
+            AussagenFunktion::IMPLIKATION(links, rechts) => {
+                let links = self.forme(links);
+                let links = self.nicht(links);
+                let rechts = self.forme(rechts);
+                self.oder(vec![links, rechts])
+            }
+            AussagenFunktion::BIIMPLIKATION(links, rechts) => {
+                let links = self.forme(links);
+                let rechts = self.forme(rechts);
+                self.biimplikation(links, rechts)
+            }
+        }
+    }
+}
+
+/// Sammelt die Operanden verschachtelter ⊕, damit auch geklammerte Ketten als ausgeglichener Baum geformt werden.
+fn xor_operanden<'a>(funktionen: &'a [Box<AussagenFunktion>], operanden: &mut Vec<&'a AussagenFunktion>) {
+    for funktion in funktionen {
+        match &**funktion {
+            AussagenFunktion::XOR(innere) => xor_operanden(innere, operanden),
+            funktion => operanden.push(funktion),
+        }
+    }
+}
+
+/// Die Anzahl der ↑- und ↓-Knoten in der Formel, also die Gatter, wenn jeder Teilterm eigene Gatter bekommt.
+pub fn anzahl_gatter(funktion: &AussagenFunktion) -> usize {
+    match funktion {
+        AussagenFunktion::VARIABEL(_) | AussagenFunktion::TOP() | AussagenFunktion::BOTTOM() => 0,
+        AussagenFunktion::NOT(inner) => anzahl_gatter(inner),
+        AussagenFunktion::IMPLIKATION(links, rechts) | AussagenFunktion::BIIMPLIKATION(links, rechts) => {
+            anzahl_gatter(links) + anzahl_gatter(rechts)
+        }
+        AussagenFunktion::AND(funktionen) | AussagenFunktion::OR(funktionen) | AussagenFunktion::XOR(funktionen) => {
+            funktionen.iter().map(|funktion| anzahl_gatter(funktion)).sum()
+        }
+        AussagenFunktion::NAND(funktionen) | AussagenFunktion::NOR(funktionen) => {
+            1 + funktionen.iter().map(|funktion| anzahl_gatter(funktion)).sum::<usize>()
+        }
+    }
+}
+
+/// Ein Eingang eines Gatters: eine Variable, eine Konstante oder der Ausgang eines anderen Gatters.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Signal {
+    Eingang(String),
+    Konstante(bool),
+    /// Der Index des Gatters in [`Schaltung::gatter`].
+    Gatter(usize),
+}
+
+impl Display for Signal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Signal::Eingang(name) => write!(f, "{}", name),
+            Signal::Konstante(true) => write!(f, "⊤"),
+            Signal::Konstante(false) => write!(f, "⊥"),
+            Signal::Gatter(index) => write!(f, "g{}", index + 1),
+        }
+    }
+}
+
+/// Eine Schaltung aus Gattern eines Typs, in der gleiche Teilterme nur ein Gatter bekommen.
+/// Jedes Gatter hängt nur von Gattern mit kleinerem Index ab.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Schaltung {
+    pub typ: Gattertyp,
+    pub gatter: Vec<Vec<Signal>>,
+    pub ausgang: Signal,
+}
+
+impl Schaltung {
+    /// Formt die Funktion in eine Schaltung aus Gattern des Typs um, siehe [`AussagenFunktion::to_nand`].
+    pub fn aus_funktion(kontext: &FormelKontext, funktion: &AussagenFunktion, typ: Gattertyp) -> Schaltung {
+        let mut umformung = Umformung {
+            kontext,
+            aufbau: Aufbau::new(typ),
+            referenzen: HashMap::new(),
+        };
+        let ausgang = umformung.forme(funktion);
+        umformung.aufbau.fertig(ausgang)
+    }
+
+    /// Baut die Schaltung aus einer Formel, die nur ↑ (oder nur ↓) verwendet. Gleiche Teilterme bekommen nur ein
+    /// Gatter.
+    pub fn new(funktion: &AussagenFunktion, typ: Gattertyp) -> Result<Schaltung, GatterFehler> {
+        let mut aufbau = Aufbau::new(typ);
+        let ausgang = aufbau.signal(funktion)?;
+        Ok(aufbau.fertig(ausgang))
+    }
+
+    /// Schreibt die Schaltung als Formel aus, in der jeder Teilterm wieder eigene Gatter bekommt.
+    pub fn to_funktion(&self) -> AussagenFunktion {
+        self.baum(&self.ausgang)
+    }
+
+    fn baum(&self, signal: &Signal) -> AussagenFunktion {
+        match signal {
+            Signal::Eingang(name) => AussagenFunktion::VARIABEL(name.clone()),
+            Signal::Konstante(true) => AussagenFunktion::TOP(),
+            Signal::Konstante(false) => AussagenFunktion::BOTTOM(),
+            Signal::Gatter(index) => {
+                let eingaenge = self.gatter[*index]
+                    .iter()
+                    .map(|eingang| Box::new(self.baum(eingang)))
+                    .collect();
+                match self.typ {
+                    Gattertyp::Nand => AussagenFunktion::NAND(eingaenge),
+                    Gattertyp::Nor => AussagenFunktion::NOR(eingaenge),
+                }
+            }
+        }
+    }
+}
+
+/// Schreibt jedes Gatter als `gN = a ↑ b` in einer Zeile und zum Schluss den Ausgang.
+impl Display for Schaltung {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let trenner = format!(" {} ", self.typ.symbol());
+        for (index, eingaenge) in self.gatter.iter().enumerate() {
+            let eingaenge: Vec<String> = eingaenge.iter().map(|signal| signal.to_string()).collect();
+            writeln!(f, "{} = {}", Signal::Gatter(index), eingaenge.join(&trenner))?;
+        }
+        write!(f, "Ausgang: {}", self.ausgang)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::aussagen::gatter::{anzahl_gatter, GatterFehler, Gattertyp, Schaltung};
+    use crate::aussagen::is_aequivalent;
+    use crate::aussagen::parsing::parse_function;
+    use crate::aussagen::structures::{AussagenFunktion, FormelKontext};
+
+    /// Ob die Formel außer Variabeln und Konstanten nur den einen Junktor enthält.
+    fn nur(funktion: &AussagenFunktion, typ: Gattertyp) -> bool {
+        match (funktion, typ) {
+            (AussagenFunktion::VARIABEL(_) | AussagenFunktion::TOP() | AussagenFunktion::BOTTOM(), _) => true,
+            (AussagenFunktion::NAND(funktionen), Gattertyp::Nand) | (AussagenFunktion::NOR(funktionen), Gattertyp::Nor) => {
+                funktionen.iter().all(|funktion| nur(funktion, typ))
+            }
+            _ => false,
+        }
+    }
+
+    #[test]
+    fn aequivalent_test() {
+        let mut kontext = FormelKontext::new();
+        kontext
+            .funktionen
+            .insert(String::from("phi"), *parse_function("A ^ B ^ C").expect("Fehler bei parse"));
+        let formeln = [
+            "-A",
+            "A & B & C",
+            "A | B",
+            "A -> B",
+            "A <-> B",
+            "(A !& B) !& C",
+            "A !| B",
+            "phi | -(D <-> t)",
+            "(A & f) | -(B -> C) ^ D",
+        ];
+        for formel in formeln {
+            let funktion = parse_function(formel).expect("Fehler bei parse");
+            let nand = funktion.to_nand(&kontext);
+            let nor = funktion.to_nor(&kontext);
+            assert!(nur(&nand, Gattertyp::Nand), "{} ist nicht nur ↑", nand);
+            assert!(nur(&nor, Gattertyp::Nor), "{} ist nicht nur ↓", nor);
+            assert!(is_aequivalent(&kontext, vec![&funktion, &nand]), "{} ≢ {}", funktion, nand);
+            assert!(is_aequivalent(&kontext, vec![&funktion, &nor]), "{} ≢ {}", funktion, nor);
+        }
+    }
+
+    #[test]
+    fn form_test() {
+        let kontext = FormelKontext::new();
+        let funktion = parse_function("-A & B").expect("Fehler bei parse");
+        assert_eq!(funktion.to_nand(&kontext).to_utf_string(), "(((A ↑ A) ↑ B) ↑ ((A ↑ A) ↑ B))");
+        assert_eq!(funktion.to_nor(&kontext).to_utf_string(), "(A ↓ (B ↓ B))");
+        let funktion = parse_function("-(A !& B)").expect("Fehler bei parse");
+        assert_eq!(funktion.to_nand(&kontext).to_utf_string(), "((A ↑ B) ↑ (A ↑ B))");
+    }
+
+    #[test]
+    fn schaltung_test() {
+        let kontext = FormelKontext::new();
+        let funktion = parse_function("A ^ B").expect("Fehler bei parse");
+        let nand = funktion.to_nand(&kontext);
+        assert_eq!(anzahl_gatter(&nand), 5);
+        let schaltung = Schaltung::new(&nand, Gattertyp::Nand).expect("Nur ↑");
+        assert_eq!(schaltung.gatter.len(), 4);
+        assert_eq!(schaltung, Schaltung::aus_funktion(&kontext, &funktion, Gattertyp::Nand));
+        assert_eq!(
+            format!("{}", schaltung),
+            "g1 = A ↑ B\ng2 = A ↑ g1\ng3 = B ↑ g1\ng4 = g2 ↑ g3\nAusgang: g4"
+        );
+
+        let funktion = parse_function("(A & B) | (B & A) | -(A & B)").expect("Fehler bei parse");
+        let nor = funktion.to_nor(&kontext);
+        let schaltung = Schaltung::new(&nor, Gattertyp::Nor).expect("Nur ↓");
+        assert!(schaltung.gatter.len() < anzahl_gatter(&nor));
+
+        assert_eq!(
+            Schaltung::new(&nor, Gattertyp::Nand),
+            Err(GatterFehler::FalscherJunktor(nor.to_utf_string(), Gattertyp::Nand))
+        );
+    }
+
+    #[test]
+    fn kette_test() {
+        // Die Umformung darf die Teilterme nicht erst als Baum ausschreiben, sonst wächst sie exponentiell.
+        let mut kontext = FormelKontext::new();
+        let variablen: Vec<String> = (0..64).map(|i| format!("X{}", i)).collect();
+        let funktion = parse_function(&variablen.join(" ^ ")).expect("Fehler bei parse");
+        let schaltung = Schaltung::aus_funktion(&kontext, &funktion, Gattertyp::Nand);
+        assert_eq!(schaltung.gatter.len(), 4 * 63);
+        // Ausgeschrieben kommt jede Variable des ausgeglichenen Baums 3^6-mal vor, auch wenn die Kette geklammert ist.
+        let geklammert = parse_function(&format!("({}) ^ {}", variablen[..63].join(" ^ "), variablen[63]))
+            .expect("Fehler bei parse");
+        for funktion in [&funktion, &geklammert] {
+            let formel = Schaltung::aus_funktion(&kontext, funktion, Gattertyp::Nand).to_funktion();
+            assert_eq!(formel.to_ascii_string().matches("X0").count(), 729);
+        }
+
+        kontext.funktionen.insert(String::from("phi0"), *parse_function("A <-> B").expect("Fehler bei parse"));
+        for i in 1..64 {
+            let formel = format!("phi{} & -phi{}", i - 1, i - 1);
+            kontext.funktionen.insert(format!("phi{}", i), *parse_function(&formel).expect("Fehler bei parse"));
+        }
+        let funktion = parse_function("phi63").expect("Fehler bei parse");
+        let schaltung = Schaltung::aus_funktion(&kontext, &funktion, Gattertyp::Nor);
+        assert!(schaltung.gatter.len() < 4 * 64);
+    }
+}
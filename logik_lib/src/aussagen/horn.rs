@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+use crate::aussagen::klauseln::{Klausel, KlauselMenge};
+use crate::aussagen::structures::{AussagenFunktion, FormelKontext};
+
+#[derive(Debug, PartialEq)]
+pub enum HornFehler {
+    /// Die Klausel hat mehr als ein positives Literal.
+    KeineHornKlausel(Klausel),
+}
+
+impl Display for HornFehler {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HornFehler::KeineHornKlausel(klausel) => write!(
+                f,
+                "Keine Horn-Formel: Die Klausel {} hat mehr als ein positives Literal",
+                klausel
+            ),
+        }
+    }
+}
+
+impl Klausel {
+    /// Eine Horn-Klausel hat höchstens ein positives Literal.
+    pub fn ist_horn(&self) -> bool {
+        self.literale.iter().filter(|literal| !literal.negiert).count() <= 1
+    }
+}
+
+impl KlauselMenge {
+    pub fn ist_horn(&self) -> bool {
+        self.klauseln.iter().all(Klausel::ist_horn)
+    }
+}
+
+/// Markierungsalgorithmus für Horn-Formeln in linearer Zeit zur Größe der Klauselmenge.
+/// Für jede Klausel wird gezählt, wie viele negative Literale noch nicht markiert sind. Fällt der Zähler auf 0,
+/// wird das positive Literal markiert. Hat die Klausel keines, ist die Klauselmenge unerfüllbar.
+/// Gibt das minimale Modell zurück: genau die markierten Variabeln sind wahr.
+/// Wie die Klauselmenge aus einer Formel entsteht, steht bei [`AussagenFunktion::minimales_modell`].
+pub fn minimales_modell(menge: &KlauselMenge) -> Result<Option<HashMap<String, bool>>, HornFehler> {
+    if let Some(klausel) = menge.klauseln.iter().find(|klausel| !klausel.ist_horn()) {
+        return Err(HornFehler::KeineHornKlausel(klausel.clone()));
+    }
+    let variablen = menge.variablen();
+    let index: HashMap<&str, usize> = variablen
+        .iter()
+        .enumerate()
+        .map(|(i, name)| (name.as_str(), i))
+        .collect();
+
+    let mut offen = vec![0usize; menge.klauseln.len()];
+    let mut kopf = vec![None; menge.klauseln.len()];
+    let mut vorkommen: Vec<Vec<usize>> = vec![Vec::new(); variablen.len()];
+    for (k, klausel) in menge.klauseln.iter().enumerate() {
+        for literal in &klausel.literale {
+            let variable = index[literal.name.as_str()];
+            if literal.negiert {
+                offen[k] += 1;
+                vorkommen[variable].push(k);
+            } else {
+                kopf[k] = Some(variable);
+            }
+        }
+    }
+
+    let mut wahr = vec![false; variablen.len()];
+    let mut bereit: Vec<usize> = (0..menge.klauseln.len()).filter(|&k| offen[k] == 0).collect();
+    while let Some(k) = bereit.pop() {
+        let variable = match kopf[k] {
+            None => return Ok(None),
+            Some(variable) => variable,
+        };
+        if wahr[variable] {
+            continue;
+        }
+        wahr[variable] = true;
+        for &klausel in &vorkommen[variable] {
+            offen[klausel] -= 1;
+            if offen[klausel] == 0 {
+                bereit.push(klausel);
+            }
+        }
+    }
+
+    Ok(Some(variablen.into_iter().zip(wahr).collect()))
+}
+
+impl AussagenFunktion {
+    /// Die Klauselmenge, auf der Horn-Formeln geprüft werden. Ist die Funktion schon eine Konjunktion von Klauseln,
+    /// wird sie mit [`Self::to_klauselform`] ohne Ausmultiplizieren übernommen, sonst mit [`Self::to_knf`], das
+    /// exponentiell wachsen kann.
+    fn horn_klauseln(&self, kontext: &FormelKontext) -> KlauselMenge {
+        self.to_klauselform(kontext, usize::MAX).unwrap_or_else(|| self.to_knf(kontext))
+    }
+
+    /// Ob die Klauselmenge der Funktion nur Horn-Klauseln enthält.
+    pub fn ist_horn(&self, kontext: &FormelKontext) -> bool {
+        self.horn_klauseln(kontext).ist_horn()
+    }
+
+    /// Das minimale Modell der Funktion über ihrer Klauselmenge, `None` wenn sie unerfüllbar ist.
+    /// Variabeln, die in der Klauselmenge nicht mehr vorkommen, sind falsch.
+    pub fn minimales_modell(&self, kontext: &FormelKontext) -> Result<Option<HashMap<String, bool>>, HornFehler> {
+        let mut modell = match minimales_modell(&self.horn_klauseln(kontext))? {
+            None => return Ok(None),
+            Some(modell) => modell,
+        };
+        for name in self.get_keys(kontext) {
+            modell.entry(name.clone()).or_insert(false);
+        }
+        Ok(Some(modell))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use crate::aussagen::horn::HornFehler;
+    use crate::aussagen::klauseln::{Klausel, Literal};
+    use crate::aussagen::parsing::parse_function;
+    use crate::aussagen::structures::FormelKontext;
+
+    #[test]
+    fn ist_horn_test() {
+        let kontext = FormelKontext::new();
+        let funktion = parse_function("(A & B -> C) & (C -> D) & A & -(D & E)").expect("Fehler bei parse");
+        assert!(funktion.ist_horn(&kontext));
+        let funktion = parse_function("(A -> B | C) & A").expect("Fehler bei parse");
+        assert!(!funktion.ist_horn(&kontext));
+        assert_eq!(
+            funktion.minimales_modell(&kontext),
+            Err(HornFehler::KeineHornKlausel(Klausel::new(vec![
+                Literal::negativ("A"),
+                Literal::positiv("B"),
+                Literal::positiv("C"),
+            ])))
+        );
+    }
+
+    #[test]
+    fn minimales_modell_test() {
+        let kontext = FormelKontext::new();
+        let funktion = parse_function("(A & B -> C) & (C -> D) & A & B & (E -> A) & (F -> F)").expect("Fehler bei parse");
+        let modell = funktion.minimales_modell(&kontext).unwrap().expect("Kein Modell");
+        let erwartet: HashMap<String, bool> = [("A", true), ("B", true), ("C", true), ("D", true), ("E", false), ("F", false)]
+            .into_iter()
+            .map(|(name, wert)| (String::from(name), wert))
+            .collect();
+        assert_eq!(modell, erwartet);
+
+        let funktion = parse_function("(A & B -> C) & (C -> D) & A & B & -(D & A)").expect("Fehler bei parse");
+        assert_eq!(funktion.minimales_modell(&kontext), Ok(None), "Sollte unerfüllbar sein");
+    }
+
+    #[test]
+    fn kette_test() {
+        let mut formel = String::from("X0");
+        for i in 0..5000 {
+            formel.push_str(&format!(" & (X{} -> X{})", i, i + 1));
+        }
+        formel.push_str(" & -X5000");
+        let kontext = FormelKontext::new();
+        let funktion = parse_function(&formel).expect("Fehler bei parse");
+        // Die Formel ist schon eine Konjunktion von Klauseln und wird ohne Ausmultiplizieren übernommen.
+        assert_eq!(funktion.to_klauselform(&kontext, usize::MAX).map(|menge| menge.klauseln.len()), Some(5002));
+        assert!(funktion.ist_horn(&kontext));
+        assert_eq!(funktion.minimales_modell(&kontext), Ok(None), "Sollte unerfüllbar sein");
+    }
+}
@@ -0,0 +1,257 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+use crate::aussagen::minimierung::Minimierung;
+use crate::aussagen::structures::{AussagenFunktion, FormelKontext};
+
+pub const MIN_VARIABLEN: usize = 2;
+pub const MAX_VARIABLEN: usize = 6;
+
+#[derive(Debug, PartialEq)]
+pub enum KarnaughFehler {
+    FalscheAnzahlVariablen(usize),
+    AndereVariablen,
+}
+
+impl Display for KarnaughFehler {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KarnaughFehler::FalscheAnzahlVariablen(anzahl) => write!(
+                f,
+                "Ein KV-Diagramm braucht {} bis {} Variabeln, die Formel hat {}",
+                MIN_VARIABLEN, MAX_VARIABLEN, anzahl
+            ),
+            KarnaughFehler::AndereVariablen => {
+                write!(f, "Die Minimierung gehört zu anderen Variabeln als das KV-Diagramm")
+            }
+        }
+    }
+}
+
+/// Ein Block des KV-Diagramms, der zu einem Implikanten gehört.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct KarnaughGruppe {
+    pub implikant: String,
+    /// Die Zellen der Gruppe als (Zeile, Spalte).
+    pub zellen: Vec<(usize, usize)>,
+}
+
+/// Ein KV-Diagramm. Zeilen und Spalten sind im Gray-Code angeordnet, sodass sich benachbarte Zellen
+/// (auch über den Rand hinweg) in genau einer Variable unterscheiden.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct KarnaughDiagramm {
+    pub zeilen_variablen: Vec<String>,
+    pub spalten_variablen: Vec<String>,
+    /// Die Belegung der Zeilenvariabeln je Zeile, z.B. `01`.
+    pub zeilen: Vec<String>,
+    /// Die Belegung der Spaltenvariabeln je Spalte, z.B. `11`.
+    pub spalten: Vec<String>,
+    pub werte: Vec<Vec<bool>>,
+    pub gruppen: Vec<KarnaughGruppe>,
+}
+
+fn gray_code(i: usize) -> usize {
+    i ^ (i >> 1)
+}
+
+fn als_bits(wert: usize, breite: usize) -> String {
+    (0..breite)
+        .map(|i| if wert & (1 << (breite - 1 - i)) != 0 { '1' } else { '0' })
+        .collect()
+}
+
+impl KarnaughDiagramm {
+    /// Erstellt das KV-Diagramm der Funktion über ihren alphabetisch sortierten Variabeln.
+    /// Die erste Hälfte der Variabeln bestimmt die Zeile, der Rest die Spalte.
+    pub fn new(kontext: &FormelKontext, funktion: &AussagenFunktion) -> Result<KarnaughDiagramm, KarnaughFehler> {
+        let mut variablen: Vec<String> = funktion.get_keys(kontext).into_iter().cloned().collect();
+        variablen.sort();
+        let n = variablen.len();
+        if !(MIN_VARIABLEN..=MAX_VARIABLEN).contains(&n) {
+            return Err(KarnaughFehler::FalscheAnzahlVariablen(n));
+        }
+
+        let zeilen_bits = n / 2;
+        let spalten_bits = n - zeilen_bits;
+        let spalten_variablen = variablen.split_off(zeilen_bits);
+        let zeilen_variablen = variablen;
+
+        let mut diagramm = KarnaughDiagramm {
+            zeilen: (0..1 << zeilen_bits)
+                .map(|zeile| als_bits(gray_code(zeile), zeilen_bits))
+                .collect(),
+            spalten: (0..1 << spalten_bits)
+                .map(|spalte| als_bits(gray_code(spalte), spalten_bits))
+                .collect(),
+            zeilen_variablen,
+            spalten_variablen,
+            werte: Vec::new(),
+            gruppen: Vec::new(),
+        };
+
+        let mut belegung = HashMap::new();
+        for zeile in 0..diagramm.zeilen.len() {
+            let mut werte = Vec::new();
+            for spalte in 0..diagramm.spalten.len() {
+                let minterm = diagramm.minterm(zeile, spalte);
+                for (i, name) in diagramm.variablen().enumerate() {
+                    belegung.insert(name.clone(), minterm & (1 << (n - 1 - i)) != 0);
+                }
+                werte.push(funktion.result(kontext, &belegung, false));
+            }
+            diagramm.werte.push(werte);
+        }
+        Ok(diagramm)
+    }
+
+    fn variablen(&self) -> impl Iterator<Item = &String> {
+        self.zeilen_variablen.iter().chain(self.spalten_variablen.iter())
+    }
+
+    /// Der Minterm der Zelle, mit derselben Bitreihenfolge wie in der Minimierung.
+    pub fn minterm(&self, zeile: usize, spalte: usize) -> u64 {
+        ((gray_code(zeile) << self.spalten_variablen.len()) | gray_code(spalte)) as u64
+    }
+
+    /// Markiert die Implikanten der minimalen Überdeckung als Gruppen.
+    pub fn mit_gruppen(mut self, minimierung: &Minimierung) -> Result<KarnaughDiagramm, KarnaughFehler> {
+        if !self.variablen().eq(minimierung.variablen.iter()) {
+            return Err(KarnaughFehler::AndereVariablen);
+        }
+        self.gruppen = minimierung
+            .minimale_ueberdeckung
+            .iter()
+            .map(|implikant| {
+                let mut zellen = Vec::new();
+                for zeile in 0..self.zeilen.len() {
+                    for spalte in 0..self.spalten.len() {
+                        if implikant.deckt(self.minterm(zeile, spalte)) {
+                            zellen.push((zeile, spalte));
+                        }
+                    }
+                }
+                KarnaughGruppe {
+                    implikant: minimierung.to_funktion(implikant).to_utf_string(),
+                    zellen,
+                }
+            })
+            .collect();
+        Ok(self)
+    }
+
+    /// Der Inhalt einer Zelle: der Wert und die Buchstaben der Gruppen, zu denen sie gehört.
+    fn zelle(&self, zeile: usize, spalte: usize) -> String {
+        let mut inhalt = String::from(if self.werte[zeile][spalte] { "1" } else { "0" });
+        for (i, gruppe) in self.gruppen.iter().enumerate() {
+            if gruppe.zellen.contains(&(zeile, spalte)) {
+                inhalt.push(gruppen_buchstabe(i));
+            }
+        }
+        inhalt
+    }
+}
+
+fn gruppen_buchstabe(index: usize) -> char {
+    (b'a' + (index % 26) as u8) as char
+}
+
+impl Display for KarnaughDiagramm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let ecke = format!("{}\\{}", self.zeilen_variablen.concat(), self.spalten_variablen.concat());
+        let erste_breite = ecke.chars().count().max(self.zeilen_variablen.len());
+        let mut breite = self.spalten_variablen.len();
+        for zeile in 0..self.zeilen.len() {
+            for spalte in 0..self.spalten.len() {
+                breite = breite.max(self.zelle(zeile, spalte).len());
+            }
+        }
+
+        write!(f, " {:>w$} |", ecke, w = erste_breite)?;
+        for spalte in &self.spalten {
+            write!(f, " {:^w$} |", spalte, w = breite)?;
+        }
+        writeln!(f)?;
+        for (zeile, bits) in self.zeilen.iter().enumerate() {
+            write!(f, " {:>w$} |", bits, w = erste_breite)?;
+            for spalte in 0..self.spalten.len() {
+                write!(f, " {:^w$} |", self.zelle(zeile, spalte), w = breite)?;
+            }
+            writeln!(f)?;
+        }
+        for (i, gruppe) in self.gruppen.iter().enumerate() {
+            writeln!(f, " {}: {}", gruppen_buchstabe(i), gruppe.implikant)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::aussagen::karnaugh::{KarnaughDiagramm, KarnaughFehler};
+    use crate::aussagen::minimierung::minimiere;
+    use crate::aussagen::parsing::parse_function;
+    use crate::aussagen::structures::FormelKontext;
+
+    #[test]
+    fn gray_code_test() {
+        let kontext = FormelKontext::new();
+        let funktion = parse_function("A & B | C & -D").expect("Fehler bei parse");
+        let diagramm = KarnaughDiagramm::new(&kontext, &funktion).expect("Kein Diagramm");
+
+        assert_eq!(diagramm.zeilen_variablen, vec!["A", "B"]);
+        assert_eq!(diagramm.spalten_variablen, vec!["C", "D"]);
+        assert_eq!(diagramm.zeilen, vec!["00", "01", "11", "10"]);
+        assert_eq!(diagramm.spalten, vec!["00", "01", "11", "10"]);
+        assert_eq!(diagramm.werte[2], vec![true, true, true, true]);
+        assert_eq!(diagramm.werte[0], vec![false, false, false, true]);
+    }
+
+    #[test]
+    fn sechs_variablen_test() {
+        let kontext = FormelKontext::new();
+        let funktion = parse_function("A ^ B ^ C ^ D ^ E ^ F").expect("Fehler bei parse");
+        let diagramm = KarnaughDiagramm::new(&kontext, &funktion).expect("Kein Diagramm");
+        assert_eq!(diagramm.werte.len(), 8);
+        assert_eq!(diagramm.werte[0].len(), 8);
+        // Benachbarte Zellen unterscheiden sich in einer Variable, die Parität wechselt also immer.
+        for zeile in 0..8 {
+            for spalte in 0..8 {
+                assert_ne!(diagramm.werte[zeile][spalte], diagramm.werte[zeile][(spalte + 1) % 8]);
+                assert_ne!(diagramm.werte[zeile][spalte], diagramm.werte[(zeile + 1) % 8][spalte]);
+            }
+        }
+
+        let funktion = parse_function("A").expect("Fehler bei parse");
+        assert_eq!(
+            KarnaughDiagramm::new(&kontext, &funktion),
+            Err(KarnaughFehler::FalscheAnzahlVariablen(1))
+        );
+    }
+
+    #[test]
+    fn gruppen_test() {
+        let kontext = FormelKontext::new();
+        let funktion = parse_function("(A & B) | (A & -B) | (-A & B & C)").expect("Fehler bei parse");
+        let diagramm = KarnaughDiagramm::new(&kontext, &funktion)
+            .expect("Kein Diagramm")
+            .mit_gruppen(&minimiere(&kontext, &funktion).expect("Zu viele Variabeln"))
+            .expect("Keine Gruppen");
+
+        assert_eq!(diagramm.gruppen.len(), 2);
+        assert_eq!(diagramm.gruppen[0].implikant, "A");
+        assert_eq!(diagramm.gruppen[0].zellen, vec![(1, 0), (1, 1), (1, 2), (1, 3)]);
+        assert_eq!(diagramm.gruppen[1].zellen, vec![(0, 2), (1, 2)]);
+        assert_eq!(
+            format!("{}", diagramm),
+            concat!(
+                " A\\BC | 00  | 01  | 11  | 10  |\n",
+                "    0 |  0  |  0  | 1b  |  0  |\n",
+                "    1 | 1a  | 1a  | 1ab | 1a  |\n",
+                " a: A\n",
+                " b: (B ⋀ C)\n"
+            )
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+use crate::aussagen::ist_erfuellbar;
+use crate::aussagen::structures::{werte_to_string, AussagenFunktion, FormelKontext};
+
+/// Ob eine Funktion immer, nie oder nur manchmal wahr ist, jeweils mit Belegungen als Zeugen.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Klassifikation {
+    Tautologie {
+        erfuellend: HashMap<String, bool>,
+    },
+    Kontradiktion {
+        widerlegend: HashMap<String, bool>,
+    },
+    Kontingent {
+        erfuellend: HashMap<String, bool>,
+        widerlegend: HashMap<String, bool>,
+    },
+}
+
+impl Display for Klassifikation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Klassifikation::Tautologie { erfuellend } => {
+                write!(f, "Tautologie\nErfüllt von: {}", werte_to_string(erfuellend))
+            }
+            Klassifikation::Kontradiktion { widerlegend } => {
+                write!(f, "Kontradiktion\nWiderlegt von: {}", werte_to_string(widerlegend))
+            }
+            Klassifikation::Kontingent {
+                erfuellend,
+                widerlegend,
+            } => write!(
+                f,
+                "Kontingent\nErfüllt von: {}\nWiderlegt von: {}",
+                werte_to_string(erfuellend),
+                werte_to_string(widerlegend)
+            ),
+        }
+    }
+}
+
+/// Klassifiziert die Funktion mit höchstens zwei Aufrufen des SAT-Solvers, für φ und für ¬φ.
+pub fn klassifiziere(kontext: &FormelKontext, funktion: &AussagenFunktion) -> Klassifikation {
+    let erfuellend = match ist_erfuellbar(kontext, funktion) {
+        Some(erfuellend) => erfuellend,
+        None => {
+            // Eine unerfüllbare Funktion wird von jeder Belegung widerlegt.
+            let widerlegend = funktion
+                .get_keys(kontext)
+                .into_iter()
+                .map(|name| (name.clone(), false))
+                .collect();
+            return Klassifikation::Kontradiktion { widerlegend };
+        }
+    };
+    let negation = AussagenFunktion::NOT(Box::new(funktion.clone()));
+    match ist_erfuellbar(kontext, &negation) {
+        None => Klassifikation::Tautologie { erfuellend },
+        Some(widerlegend) => Klassifikation::Kontingent {
+            erfuellend,
+            widerlegend,
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::aussagen::klassifikation::{klassifiziere, Klassifikation};
+    use crate::aussagen::parsing::parse_function;
+    use crate::aussagen::structures::FormelKontext;
+
+    #[test]
+    fn klassifiziere_test() {
+        let kontext = FormelKontext::new();
+        let funktion = parse_function("(A -> B) <-> (-B -> -A)").expect("Fehler bei parse");
+        assert!(matches!(klassifiziere(&kontext, &funktion), Klassifikation::Tautologie { .. }));
+
+        let funktion = parse_function("A & -A").expect("Fehler bei parse");
+        match klassifiziere(&kontext, &funktion) {
+            Klassifikation::Kontradiktion { widerlegend } => assert_eq!(widerlegend.len(), 1),
+            andere => panic!("{} ist keine Kontradiktion", andere),
+        }
+
+        let funktion = parse_function("A -> B").expect("Fehler bei parse");
+        match klassifiziere(&kontext, &funktion) {
+            Klassifikation::Kontingent {
+                erfuellend,
+                widerlegend,
+            } => {
+                assert!(funktion.result(&kontext, &erfuellend, false));
+                assert!(!funktion.result(&kontext, &widerlegend, false));
+            }
+            andere => panic!("{} ist nicht kontingent", andere),
+        }
+    }
+
+    #[test]
+    fn ausgabe_test() {
+        let kontext = FormelKontext::new();
+        let funktion = parse_function("-A & -B").expect("Fehler bei parse");
+        let klassifikation = klassifiziere(&kontext, &funktion);
+        let ausgabe = format!("{}", klassifikation);
+        assert!(ausgabe.starts_with("Kontingent\nErfüllt von: A = false, B = false\nWiderlegt von: "));
+    }
+}
@@ -0,0 +1,182 @@
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+
+use crate::aussagen::structures::AussagenFunktion;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Literal {
+    pub name: String,
+    pub negiert: bool,
+}
+
+impl Literal {
+    pub fn positiv(name: &str) -> Literal {
+        Literal {
+            name: String::from(name),
+            negiert: false,
+        }
+    }
+
+    pub fn negativ(name: &str) -> Literal {
+        Literal {
+            name: String::from(name),
+            negiert: true,
+        }
+    }
+
+    pub fn negation(&self) -> Literal {
+        Literal {
+            name: self.name.clone(),
+            negiert: !self.negiert,
+        }
+    }
+
+    pub fn to_funktion(&self) -> AussagenFunktion {
+        let variable = AussagenFunktion::VARIABEL(self.name.clone());
+        if self.negiert {
+            AussagenFunktion::NOT(Box::new(variable))
+        } else {
+            variable
+        }
+    }
+}
+
+impl Display for Literal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.negiert {
+            write!(f, "¬{}", self.name)
+        } else {
+            write!(f, "{}", self.name)
+        }
+    }
+}
+
+/// Eine Disjunktion von Literalen. Die leere Klausel ist unerfüllbar.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Klausel {
+    pub literale: Vec<Literal>,
+}
+
+impl Klausel {
+    pub fn new(literale: Vec<Literal>) -> Klausel {
+        let mut klausel = Klausel::default();
+        for literal in literale {
+            klausel.add(literal);
+        }
+        klausel
+    }
+
+    /// Fügt ein Literal hinzu, wenn es noch nicht enthalten ist.
+    pub fn add(&mut self, literal: Literal) {
+        if !self.literale.contains(&literal) {
+            self.literale.push(literal);
+        }
+    }
+
+    /// Ob die Klausel ein Literal und seine Negation enthält und damit immer wahr ist.
+    pub fn ist_tautologie(&self) -> bool {
+        self.literale
+            .iter()
+            .any(|literal| self.literale.contains(&literal.negation()))
+    }
+
+    pub fn ist_leer(&self) -> bool {
+        self.literale.is_empty()
+    }
+
+    pub fn to_funktion(&self) -> AussagenFunktion {
+        if self.ist_leer() {
+            return AussagenFunktion::BOTTOM();
+        }
+        // Ein einzelnes Literal wird nicht in eine einstellige Disjunktion gepackt.
+        if let [literal] = self.literale.as_slice() {
+            return literal.to_funktion();
+        }
+        AussagenFunktion::OR(
+            self.literale
+                .iter()
+                .map(|literal| Box::new(literal.to_funktion()))
+                .collect(),
+        )
+    }
+}
+
+impl Display for Klausel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{")?;
+        for (i, literal) in self.literale.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", literal)?;
+        }
+        write!(f, "}}")
+    }
+}
+
+/// Eine Konjunktion von Klauseln. Die leere Klauselmenge ist immer wahr.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct KlauselMenge {
+    pub klauseln: Vec<Klausel>,
+}
+
+impl KlauselMenge {
+    /// Fügt eine Klausel hinzu, wenn sie keine Tautologie und noch nicht enthalten ist.
+    pub fn add(&mut self, klausel: Klausel) {
+        if !klausel.ist_tautologie() && !self.klauseln.contains(&klausel) {
+            self.klauseln.push(klausel);
+        }
+    }
+
+    pub fn extend(&mut self, menge: KlauselMenge) {
+        for klausel in menge.klauseln {
+            self.add(klausel);
+        }
+    }
+
+    /// Alle Variabeln der Klauselmenge in der Reihenfolge ihres ersten Auftretens.
+    pub fn variablen(&self) -> Vec<String> {
+        let mut variablen: Vec<String> = Vec::new();
+        let mut gesehen: HashSet<&str> = HashSet::new();
+        for klausel in &self.klauseln {
+            for literal in &klausel.literale {
+                if gesehen.insert(&literal.name) {
+                    variablen.push(literal.name.clone());
+                }
+            }
+        }
+        variablen
+    }
+
+    /// Gibt die Klauselmenge als ⋀ von ⋁ zurück. Die leere Menge wird zu ⊤, die leere Klausel zu ⊥.
+    pub fn to_funktion(&self) -> AussagenFunktion {
+        if self.klauseln.is_empty() {
+            return AussagenFunktion::TOP();
+        }
+        if let [klausel] = self.klauseln.as_slice() {
+            return klausel.to_funktion();
+        }
+        AussagenFunktion::AND(
+            self.klauseln
+                .iter()
+                .map(|klausel| Box::new(klausel.to_funktion()))
+                .collect(),
+        )
+    }
+}
+
+impl Display for KlauselMenge {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{")?;
+        for (i, klausel) in self.klauseln.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", klausel)?;
+        }
+        write!(f, "}}")
+    }
+}
@@ -0,0 +1,211 @@
+use std::iter::{Enumerate, Peekable};
+use std::ops::Range;
+use std::str::Chars;
+
+use crate::aussagen::parsing::ParseError;
+use crate::aussagen::parsing::ParseErrorKind::IncompleteOperator;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenKind {
+    Identifier(String),
+    True,
+    False,
+    Not,
+    And,
+    Or,
+    Xor,
+    Nand,
+    Nor,
+    Implies,
+    Iff,
+    LParen,
+    RParen,
+}
+
+/// Ein Token der Eingabe. `span` enthält Zeichen-Offsets (nicht Bytes), `text` die Schreibweise in der Eingabe.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub span: Range<usize>,
+    pub text: String,
+}
+
+/// Zerlegt eine Formel in Tokens.
+/// Bezeichner laufen bis zum nächsten Leerzeichen, Operator oder zur nächsten Klammer.
+/// `t`, `true`, `f` und `false` sind nur als alleinstehende Bezeichner Konstanten.
+pub fn tokenize(eingabe: &str) -> Result<Vec<Token>, ParseError> {
+    let mut tokens = Vec::new();
+    let mut zeichen = eingabe.chars().enumerate().peekable();
+    while let Some((position, x)) = zeichen.next() {
+        if x.is_whitespace() {
+            continue;
+        }
+        let token = match x {
+            '-' if zeichen.peek().map(|z| z.1) == Some('>') => {
+                lese_folge(&mut zeichen, position, x, &['>'], &["->"], TokenKind::Implies)?
+            }
+            '<' => lese_folge(&mut zeichen, position, x, &['-', '>'], &["<->"], TokenKind::Iff)?,
+            '!' => match zeichen.peek().map(|z| z.1) {
+                Some('&') => lese_folge(&mut zeichen, position, x, &['&'], &["!&"], TokenKind::Nand)?,
+                _ => lese_folge(&mut zeichen, position, x, &['|'], &["!&", "!|"], TokenKind::Nor)?,
+            },
+            x => match einzelnes_zeichen(x) {
+                Some(kind) => Token {
+                    kind,
+                    span: position..position + 1,
+                    text: String::from(x),
+                },
+                None => lese_bezeichner(&mut zeichen, position, x),
+            },
+        };
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+fn einzelnes_zeichen(x: char) -> Option<TokenKind> {
+    match x {
+        '(' => Some(TokenKind::LParen),
+        ')' => Some(TokenKind::RParen),
+        '-' | '¬' => Some(TokenKind::Not),
+        '&' | '⋀' | '∧' => Some(TokenKind::And),
+        '|' | '⋁' | '∨' => Some(TokenKind::Or),
+        '^' | '⊕' => Some(TokenKind::Xor),
+        '↑' => Some(TokenKind::Nand),
+        '↓' => Some(TokenKind::Nor),
+        '→' => Some(TokenKind::Implies),
+        '↔' => Some(TokenKind::Iff),
+        '⊤' => Some(TokenKind::True),
+        '⊥' => Some(TokenKind::False),
+        _ => None,
+    }
+}
+
+fn ist_bezeichner_zeichen(x: char) -> bool {
+    !x.is_whitespace() && !matches!(x, '<' | '!') && einzelnes_zeichen(x).is_none()
+}
+
+fn lese_bezeichner(zeichen: &mut Peekable<Enumerate<Chars>>, position: usize, x: char) -> Token {
+    let mut text = String::from(x);
+    while let Some((_, x)) = zeichen.peek() {
+        if !ist_bezeichner_zeichen(*x) {
+            break;
+        }
+        text.push(*x);
+        zeichen.next();
+    }
+    let kind = match text.as_str() {
+        "t" | "true" => TokenKind::True,
+        "f" | "false" => TokenKind::False,
+        _ => TokenKind::Identifier(text.clone()),
+    };
+    Token {
+        kind,
+        span: position..position + text.chars().count(),
+        text,
+    }
+}
+
+/// Liest einen mehrteiligen ASCII-Operator, dessen erstes Zeichen `x` bereits gelesen wurde.
+fn lese_folge(
+    zeichen: &mut Peekable<Enumerate<Chars>>,
+    position: usize,
+    x: char,
+    folge: &[char],
+    schreibweise: &[&'static str],
+    kind: TokenKind,
+) -> Result<Token, ParseError> {
+    let mut text = String::from(x);
+    for erwartetes_zeichen in folge {
+        match zeichen.peek() {
+            Some((_, z)) if z == erwartetes_zeichen => {
+                text.push(*z);
+                zeichen.next();
+            }
+            naechstes => {
+                if let Some((_, z)) = naechstes {
+                    text.push(*z);
+                }
+                let ende = position + text.chars().count();
+                return Err(ParseError {
+                    kind: IncompleteOperator,
+                    span: position..ende,
+                    found: Some(text),
+                    expected: schreibweise.to_vec(),
+                });
+            }
+        }
+    }
+    Ok(Token {
+        kind,
+        span: position..position + text.chars().count(),
+        text,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::aussagen::lexer::TokenKind::*;
+    use crate::aussagen::lexer::{tokenize, TokenKind};
+
+    fn kinds(eingabe: &str) -> Vec<TokenKind> {
+        tokenize(eingabe)
+            .expect("Fehler bei tokenize")
+            .into_iter()
+            .map(|token| token.kind)
+            .collect()
+    }
+
+    #[test]
+    fn bezeichner_mit_t_und_f_test() {
+        assert_eq!(
+            kinds("(left & flag) -> test"),
+            vec![
+                LParen,
+                Identifier(String::from("left")),
+                And,
+                Identifier(String::from("flag")),
+                RParen,
+                Implies,
+                Identifier(String::from("test")),
+            ]
+        );
+    }
+
+    #[test]
+    fn konstanten_test() {
+        assert_eq!(kinds("t | f"), vec![True, Or, False]);
+        assert_eq!(kinds("true&false"), vec![True, And, False]);
+        assert_eq!(kinds("⊤ ⋁ ⊥"), vec![True, Or, False]);
+        assert_eq!(kinds("tf"), vec![Identifier(String::from("tf"))]);
+    }
+
+    #[test]
+    fn operatoren_test() {
+        assert_eq!(
+            kinds("-A->B<->C^D!&E!|F"),
+            vec![
+                Not,
+                Identifier(String::from("A")),
+                Implies,
+                Identifier(String::from("B")),
+                Iff,
+                Identifier(String::from("C")),
+                Xor,
+                Identifier(String::from("D")),
+                Nand,
+                Identifier(String::from("E")),
+                Nor,
+                Identifier(String::from("F")),
+            ]
+        );
+    }
+
+    #[test]
+    fn span_test() {
+        let tokens = tokenize("phi1 <-> ¬x").expect("Fehler bei tokenize");
+        let spans: Vec<_> = tokens.iter().map(|token| token.span.clone()).collect();
+        assert_eq!(spans, vec![0..4, 5..8, 9..10, 10..11]);
+        assert_eq!(tokens[1].text, "<->");
+    }
+}
@@ -0,0 +1,390 @@
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::{Display, Formatter};
+
+use crate::aussagen::klauseln::Literal;
+use crate::aussagen::structures::{AussagenFunktion, FormelKontext, Wahrheitstabelle};
+
+/// Mehr Variabeln werden nicht minimiert, weil dafür jede Belegung ausgewertet wird.
+pub const MAX_VARIABLEN: usize = 16;
+
+#[derive(Debug, PartialEq)]
+pub enum MinimierungsFehler {
+    ZuVieleVariablen(usize),
+}
+
+impl Display for MinimierungsFehler {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MinimierungsFehler::ZuVieleVariablen(anzahl) => write!(
+                f,
+                "Es werden nur Funktionen mit bis zu {} Variabeln minimiert, die Formel hat {}",
+                MAX_VARIABLEN, anzahl
+            ),
+        }
+    }
+}
+
+/// Ein Implikant als Würfel über den Variabeln der Minimierung.
+/// Bit `n - 1 - i` steht für die i-te Variable, sodass das Muster wie eine Zeile der Wahrheitstabelle gelesen wird.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Implikant {
+    /// Gesetzte Bits sind Variabeln, die im Implikanten nicht vorkommen.
+    pub frei: u64,
+    /// Gesetzte Bits sind Variabeln, die positiv vorkommen.
+    pub werte: u64,
+}
+
+impl Implikant {
+    pub fn deckt(&self, minterm: u64) -> bool {
+        minterm & !self.frei == self.werte
+    }
+
+    pub fn anzahl_literale(&self, anzahl_variablen: usize) -> usize {
+        anzahl_variablen - self.frei.count_ones() as usize
+    }
+
+    /// Das Muster des Implikanten, z.B. `1-0` für A ⋀ ¬C über den Variabeln A, B, C.
+    pub fn muster(&self, anzahl_variablen: usize) -> String {
+        (0..anzahl_variablen)
+            .map(|i| {
+                let bit = 1 << (anzahl_variablen - 1 - i);
+                if self.frei & bit != 0 {
+                    '-'
+                } else if self.werte & bit != 0 {
+                    '1'
+                } else {
+                    '0'
+                }
+            })
+            .collect()
+    }
+
+    fn kombiniere(&self, anderer: &Implikant) -> Option<Implikant> {
+        let unterschied = self.werte ^ anderer.werte;
+        if self.frei != anderer.frei || unterschied.count_ones() != 1 {
+            return None;
+        }
+        Some(Implikant {
+            frei: self.frei | unterschied,
+            werte: self.werte & !unterschied,
+        })
+    }
+}
+
+/// Das Ergebnis des Quine-McCluskey-Verfahrens.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Minimierung {
+    pub variablen: Vec<String>,
+    pub minterme: Vec<u64>,
+    pub primimplikanten: Vec<Implikant>,
+    pub essentielle_primimplikanten: Vec<Implikant>,
+    /// Die Primimplikanten der minimalen Überdeckung, inklusive der essentiellen.
+    pub minimale_ueberdeckung: Vec<Implikant>,
+}
+
+/// Minimiert die Funktion über ihren Variabeln (alphabetisch sortiert).
+/// Verweise auf Funktionen aus dem Kontext werden aufgelöst.
+pub fn minimiere(kontext: &FormelKontext, funktion: &AussagenFunktion) -> Result<Minimierung, MinimierungsFehler> {
+    let mut variablen: Vec<String> = funktion.get_keys(kontext).into_iter().cloned().collect();
+    variablen.sort();
+    let n = variablen.len();
+    if n > MAX_VARIABLEN {
+        return Err(MinimierungsFehler::ZuVieleVariablen(n));
+    }
+
+    let mut minterme = Vec::new();
+    let mut werte = HashMap::new();
+    for minterm in 0..(1u64 << n) {
+        for (i, name) in variablen.iter().enumerate() {
+            werte.insert(name.clone(), minterm & (1 << (n - 1 - i)) != 0);
+        }
+        if funktion.result(kontext, &werte, false) {
+            minterme.push(minterm);
+        }
+    }
+    Ok(minimiere_minterme(variablen, minterme))
+}
+
+/// Minimiert die Ergebnisspalte `name` einer Wahrheitstabelle.
+/// Gibt `None` zurück, wenn die Funktion nicht in der Tabelle steht.
+pub fn minimiere_tabelle(tabelle: &Wahrheitstabelle, name: &str) -> Option<Minimierung> {
+    if !tabelle.reihenfolge.iter().any(|spalte| spalte == name) {
+        return None;
+    }
+    let mut variablen: Vec<String> = match tabelle.belegungen.first() {
+        Some(belegung) => belegung.werte.keys().cloned().collect(),
+        None => Vec::new(),
+    };
+    variablen.sort();
+    let n = variablen.len();
+
+    let mut minterme = Vec::new();
+    for belegung in &tabelle.belegungen {
+        if *belegung.ergebnisse.get(name)? {
+            let mut minterm = 0;
+            for (i, variable) in variablen.iter().enumerate() {
+                if belegung.werte[variable] {
+                    minterm |= 1 << (n - 1 - i);
+                }
+            }
+            minterme.push(minterm);
+        }
+    }
+    minterme.sort();
+    Some(minimiere_minterme(variablen, minterme))
+}
+
+pub fn minimiere_minterme(variablen: Vec<String>, minterme: Vec<u64>) -> Minimierung {
+    let primimplikanten = primimplikanten(&minterme);
+
+    let mut essentielle_primimplikanten: Vec<Implikant> = Vec::new();
+    for minterm in &minterme {
+        let mut deckend = primimplikanten.iter().filter(|implikant| implikant.deckt(*minterm));
+        if let (Some(implikant), None) = (deckend.next(), deckend.next()) {
+            if !essentielle_primimplikanten.contains(implikant) {
+                essentielle_primimplikanten.push(*implikant);
+            }
+        }
+    }
+    essentielle_primimplikanten.sort();
+
+    let offen: Vec<u64> = minterme
+        .iter()
+        .copied()
+        .filter(|minterm| !essentielle_primimplikanten.iter().any(|implikant| implikant.deckt(*minterm)))
+        .collect();
+    let mut minimale_ueberdeckung = essentielle_primimplikanten.clone();
+    minimale_ueberdeckung.extend(petrick(&primimplikanten, &offen, variablen.len()));
+    minimale_ueberdeckung.sort();
+
+    Minimierung {
+        variablen,
+        minterme,
+        primimplikanten,
+        essentielle_primimplikanten,
+        minimale_ueberdeckung,
+    }
+}
+
+/// Fasst die Minterme so lange paarweise zusammen, bis sich nichts mehr kombinieren lässt.
+/// Alle Implikanten, die in keiner Runde kombiniert wurden, sind Primimplikanten.
+fn primimplikanten(minterme: &[u64]) -> Vec<Implikant> {
+    let mut aktuelle: BTreeSet<Implikant> = minterme
+        .iter()
+        .map(|minterm| Implikant {
+            frei: 0,
+            werte: *minterm,
+        })
+        .collect();
+    let mut primimplikanten = Vec::new();
+
+    while !aktuelle.is_empty() {
+        let liste: Vec<Implikant> = aktuelle.into_iter().collect();
+        let mut kombiniert = vec![false; liste.len()];
+        let mut naechste = BTreeSet::new();
+        for i in 0..liste.len() {
+            for j in (i + 1)..liste.len() {
+                if let Some(implikant) = liste[i].kombiniere(&liste[j]) {
+                    naechste.insert(implikant);
+                    kombiniert[i] = true;
+                    kombiniert[j] = true;
+                }
+            }
+        }
+        for (implikant, kombiniert) in liste.into_iter().zip(kombiniert) {
+            if !kombiniert {
+                primimplikanten.push(implikant);
+            }
+        }
+        aktuelle = naechste;
+    }
+    primimplikanten.sort();
+    primimplikanten
+}
+
+/// Wählt mit dem Verfahren von Petrick möglichst wenige Primimplikanten (bei Gleichstand mit möglichst wenigen Literalen),
+/// die alle offenen Minterme überdecken.
+fn petrick(primimplikanten: &[Implikant], offen: &[u64], anzahl_variablen: usize) -> Vec<Implikant> {
+    // Jeder Term ist eine Auswahl von Primimplikanten (als Indizes), das Produkt ist eine Disjunktion solcher Terme.
+    let mut produkt: Vec<BTreeSet<usize>> = vec![BTreeSet::new()];
+    for minterm in offen {
+        let summe: Vec<usize> = (0..primimplikanten.len())
+            .filter(|i| primimplikanten[*i].deckt(*minterm))
+            .collect();
+        let mut neues_produkt: Vec<BTreeSet<usize>> = Vec::new();
+        for term in &produkt {
+            if summe.iter().any(|i| term.contains(i)) {
+                neues_produkt.push(term.clone());
+                continue;
+            }
+            for i in &summe {
+                let mut neuer_term = term.clone();
+                neuer_term.insert(*i);
+                neues_produkt.push(neuer_term);
+            }
+        }
+        // Absorption: X ⋁ (X ⋀ Y) = X
+        neues_produkt.sort_by_key(|term| term.len());
+        produkt = Vec::new();
+        for term in neues_produkt {
+            if !produkt.iter().any(|kleiner| kleiner.is_subset(&term)) {
+                produkt.push(term);
+            }
+        }
+    }
+
+    let kosten = |term: &BTreeSet<usize>| {
+        let literale: usize = term
+            .iter()
+            .map(|i| primimplikanten[*i].anzahl_literale(anzahl_variablen))
+            .sum();
+        (term.len(), literale)
+    };
+    produkt
+        .into_iter()
+        .min_by_key(kosten)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|i| primimplikanten[i])
+        .collect()
+}
+
+impl Minimierung {
+    /// Der Implikant als Konjunktion, ein einzelnes Literal bleibt ungeklammert.
+    pub fn to_funktion(&self, implikant: &Implikant) -> AussagenFunktion {
+        let n = self.variablen.len();
+        let mut literale: Vec<Box<AussagenFunktion>> = Vec::new();
+        for (i, name) in self.variablen.iter().enumerate() {
+            let bit = 1 << (n - 1 - i);
+            if implikant.frei & bit == 0 {
+                let literal = Literal {
+                    name: name.clone(),
+                    negiert: implikant.werte & bit == 0,
+                };
+                literale.push(Box::new(literal.to_funktion()));
+            }
+        }
+        match literale.len() {
+            0 => AussagenFunktion::TOP(),
+            1 => *literale.pop().unwrap(),
+            _ => AussagenFunktion::AND(literale),
+        }
+    }
+
+    /// Die minimale disjunktive Form aus der minimalen Überdeckung.
+    pub fn minimalform(&self) -> AussagenFunktion {
+        let mut terme: Vec<Box<AussagenFunktion>> = self
+            .minimale_ueberdeckung
+            .iter()
+            .map(|implikant| Box::new(self.to_funktion(implikant)))
+            .collect();
+        match terme.len() {
+            0 => AussagenFunktion::BOTTOM(),
+            1 => *terme.pop().unwrap(),
+            _ => AussagenFunktion::OR(terme),
+        }
+    }
+
+    fn schreibe_implikanten(&self, f: &mut Formatter<'_>, implikanten: &[Implikant]) -> std::fmt::Result {
+        for (i, implikant) in implikanten.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", self.to_funktion(implikant))?;
+        }
+        writeln!(f)
+    }
+}
+
+impl Display for Minimierung {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Primimplikanten: ")?;
+        self.schreibe_implikanten(f, &self.primimplikanten)?;
+        write!(f, "Essentielle Primimplikanten: ")?;
+        self.schreibe_implikanten(f, &self.essentielle_primimplikanten)?;
+        write!(f, "Minimalform: {}", self.minimalform())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::aussagen::get_wahrheitstabelle;
+    use crate::aussagen::minimierung::{minimiere, minimiere_minterme, minimiere_tabelle, Implikant, MinimierungsFehler};
+    use crate::aussagen::parsing::parse_function;
+    use crate::aussagen::structures::{AussagenFunktion, FormelKontext, Wahrheitstabelle};
+
+    fn variablen(namen: &[&str]) -> Vec<String> {
+        namen.iter().map(|name| String::from(*name)).collect()
+    }
+
+    #[test]
+    fn lehrbuch_beispiel_test() {
+        // f(A, B, C, D) = Σm(4, 8, 10, 11, 12, 15)
+        let minimierung = minimiere_minterme(variablen(&["A", "B", "C", "D"]), vec![4, 8, 10, 11, 12, 15]);
+        let muster: Vec<String> = minimierung
+            .primimplikanten
+            .iter()
+            .map(|implikant| implikant.muster(4))
+            .collect();
+        assert_eq!(muster.len(), 5);
+        for erwartet in ["-100", "1-00", "10-0", "101-", "1-11"] {
+            assert!(muster.contains(&String::from(erwartet)), "{} fehlt in {:?}", erwartet, muster);
+        }
+        assert_eq!(
+            minimierung.essentielle_primimplikanten,
+            vec![
+                Implikant { frei: 0b0100, werte: 0b1011 },
+                Implikant { frei: 0b1000, werte: 0b0100 }
+            ]
+        );
+        assert_eq!(minimierung.minimale_ueberdeckung.len(), 3);
+        assert_eq!(minimierung.minimalform().to_ascii_string(), "((A & -B & -D) | (A & C & D) | (B & -C & -D))");
+    }
+
+    #[test]
+    fn petrick_test() {
+        // Zyklische Überdeckung ohne essentielle Primimplikanten: Σm(0, 1, 2, 5, 6, 7)
+        let minimierung = minimiere_minterme(variablen(&["A", "B", "C"]), vec![0, 1, 2, 5, 6, 7]);
+        assert_eq!(minimierung.primimplikanten.len(), 6);
+        assert!(minimierung.essentielle_primimplikanten.is_empty());
+        assert_eq!(minimierung.minimale_ueberdeckung.len(), 3);
+    }
+
+    #[test]
+    fn formel_test() {
+        let kontext = FormelKontext::new();
+        let funktion = parse_function("(A & B) | (A & -B) | (-A & B & C)").expect("Fehler bei parse");
+        let minimierung = minimiere(&kontext, &funktion).expect("Zu viele Variabeln");
+        assert_eq!(minimierung.minimalform().to_ascii_string(), "(A | (B & C))");
+
+        let tautologie = parse_function("A | -A").expect("Fehler bei parse");
+        assert_eq!(minimiere(&kontext, &tautologie).unwrap().minimalform(), AussagenFunktion::TOP());
+        let widerspruch = parse_function("A & -A").expect("Fehler bei parse");
+        assert_eq!(minimiere(&kontext, &widerspruch).unwrap().minimalform(), AussagenFunktion::BOTTOM());
+
+        let namen: Vec<String> = (0..64).map(|i| format!("x{}", i)).collect();
+        let funktion = parse_function(&namen.join(" | ")).expect("Fehler bei parse");
+        assert_eq!(minimiere(&kontext, &funktion), Err(MinimierungsFehler::ZuVieleVariablen(64)));
+    }
+
+    #[test]
+    fn tabelle_test() {
+        let mut kontext = FormelKontext::new();
+        kontext
+            .funktionen
+            .insert(String::from("phi"), *parse_function("A -> B").expect("Fehler bei parse"));
+        let phi = kontext.funktionen.get("phi").unwrap();
+        let tabelle = get_wahrheitstabelle(&kontext, vec![phi]);
+
+        let minimierung = minimiere_tabelle(&tabelle, "phi").expect("Nicht in der Tabelle");
+        assert_eq!(minimierung.minimalform().to_ascii_string(), "(-A | B)");
+        assert_eq!(Ok(minimierung), minimiere(&kontext, phi));
+        assert!(minimiere_tabelle(&tabelle, "psi").is_none());
+
+        let leer = Wahrheitstabelle {
+            belegungen: Vec::new(),
+            reihenfolge: Vec::new(),
+        };
+        assert!(minimiere_tabelle(&leer, "phi").is_none());
+    }
+}
@@ -0,0 +1,176 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+use crate::aussagen::klauseln::{Klausel, Literal};
+use crate::aussagen::sat::SatSolver;
+use crate::aussagen::structures::{AussagenFunktion, Belegung, FormelKontext};
+
+#[derive(Debug, PartialEq)]
+pub enum ModellFehler {
+    /// Die Variable der Projektion kommt in der Funktion nicht vor.
+    UnbekannteVariable(String),
+}
+
+impl Display for ModellFehler {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ModellFehler::UnbekannteVariable(name) => {
+                write!(f, "Die Variable {} kommt in der Funktion nicht vor", name)
+            }
+        }
+    }
+}
+
+/// Zählt die Modelle einer Funktion nacheinander mit dem SAT-Solver auf. Jedes gefundene Modell wird durch eine
+/// Klausel ausgeschlossen, deshalb wird erst beim Aufruf von `next` weitergesucht.
+///
+/// Mit einer Projektion werden nur die Werte dieser Variabeln geliefert, und jede Belegung von ihnen kommt nur einmal vor.
+pub struct ModellIterator {
+    solver: SatSolver,
+    variablen: Vec<String>,
+    /// Der Name, unter dem die Ergebnisse stehen.
+    name: Option<String>,
+    limit: Option<usize>,
+    gefunden: usize,
+    fertig: bool,
+}
+
+impl ModellIterator {
+    /// Ohne `projektion` wird über alle Variabeln der Funktion aufgezählt, nach Namen sortiert.
+    /// Mit `limit` endet der Iterator nach so vielen Modellen. Mit `name` steht in den Ergebnissen jedes Modells,
+    /// dass die Funktion unter diesem Namen wahr ist.
+    pub fn new(
+        kontext: &FormelKontext,
+        funktion: &AussagenFunktion,
+        name: Option<&str>,
+        projektion: Option<Vec<String>>,
+        limit: Option<usize>,
+    ) -> Result<ModellIterator, ModellFehler> {
+        let keys = funktion.get_keys(kontext);
+        let variablen = match projektion {
+            Some(projektion) => {
+                if let Some(name) = projektion.iter().find(|name| !keys.contains(name)) {
+                    return Err(ModellFehler::UnbekannteVariable(name.clone()));
+                }
+                projektion
+            }
+            None => {
+                let mut variablen: Vec<String> = keys.into_iter().cloned().collect();
+                variablen.sort();
+                variablen
+            }
+        };
+        let mut solver = SatSolver::new();
+        solver.add_klauseln(&funktion.to_knf_tseitin(kontext));
+        for variable in &variablen {
+            solver.add_variable(variable);
+        }
+        Ok(ModellIterator {
+            solver,
+            variablen,
+            name: name.map(String::from),
+            limit,
+            gefunden: 0,
+            fertig: false,
+        })
+    }
+}
+
+impl Iterator for ModellIterator {
+    type Item = Belegung;
+
+    fn next(&mut self) -> Option<Belegung> {
+        if self.fertig || self.limit.is_some_and(|limit| self.gefunden >= limit) {
+            return None;
+        }
+        let modell = match self.solver.loese() {
+            Some(modell) => modell,
+            None => {
+                self.fertig = true;
+                return None;
+            }
+        };
+        let werte: HashMap<String, bool> = self
+            .variablen
+            .iter()
+            .map(|name| (name.clone(), modell[name]))
+            .collect();
+        if werte.is_empty() {
+            self.fertig = true;
+        }
+        let blockiert = werte
+            .iter()
+            .map(|(name, wert)| Literal {
+                name: name.clone(),
+                negiert: *wert,
+            })
+            .collect();
+        self.solver.add_klausel(&Klausel::new(blockiert));
+        self.gefunden += 1;
+
+        let ergebnisse = match &self.name {
+            Some(name) => HashMap::from([(name.clone(), true)]),
+            None => HashMap::new(),
+        };
+        Some(Belegung { werte, ergebnisse })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::aussagen::modelle::{ModellFehler, ModellIterator};
+    use crate::aussagen::parsing::parse_function;
+    use crate::aussagen::structures::FormelKontext;
+
+    #[test]
+    fn alle_modelle_test() {
+        let kontext = FormelKontext::new();
+        let funktion = parse_function("(A | B) & (B -> C)").expect("Fehler bei parse");
+        let modelle: Vec<_> = ModellIterator::new(&kontext, &funktion, None, None, None).unwrap().collect();
+        assert_eq!(modelle.len(), 4);
+        for belegung in &modelle {
+            assert!(funktion.result(&kontext, &belegung.werte, false));
+            assert_eq!(belegung.werte.len(), 3);
+        }
+        for (i, belegung) in modelle.iter().enumerate() {
+            assert!(!modelle[..i].iter().any(|andere| andere.werte == belegung.werte));
+        }
+
+        let funktion = parse_function("A & -A").expect("Fehler bei parse");
+        assert_eq!(ModellIterator::new(&kontext, &funktion, None, None, None).unwrap().count(), 0);
+        let funktion = parse_function("t").expect("Fehler bei parse");
+        assert_eq!(ModellIterator::new(&kontext, &funktion, None, None, None).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn projektion_test() {
+        let mut kontext = FormelKontext::new();
+        // psi hat dieselbe Struktur wie phi, die Ergebnisse müssen trotzdem unter dem übergebenen Namen stehen.
+        for name in ["phi", "psi"] {
+            kontext
+                .funktionen
+                .insert(String::from(name), *parse_function("A ^ B ^ C").expect("Fehler bei parse"));
+        }
+        let funktion = kontext.funktionen.get("psi").unwrap();
+        let modelle: Vec<_> =
+            ModellIterator::new(&kontext, funktion, Some("psi"), Some(vec![String::from("A")]), None).unwrap().collect();
+        assert_eq!(modelle.len(), 2);
+        assert_eq!(modelle[0].werte.len(), 1);
+        assert!(modelle[0].ergebnisse["psi"]);
+        assert_eq!(modelle[0].ergebnisse.len(), 1);
+
+        let fehler = ModellIterator::new(&kontext, funktion, Some("psi"), Some(vec![String::from("D")]), None).err();
+        assert_eq!(fehler, Some(ModellFehler::UnbekannteVariable(String::from("D"))));
+    }
+
+    #[test]
+    fn limit_test() {
+        let kontext = FormelKontext::new();
+        let namen: Vec<String> = (0..50).map(|i| format!("x{}", i)).collect();
+        let funktion = parse_function(&namen.join(" | ")).expect("Fehler bei parse");
+        let modelle = ModellIterator::new(&kontext, &funktion, None, None, Some(5)).unwrap();
+        assert_eq!(modelle.count(), 5);
+        let mut modelle = ModellIterator::new(&kontext, &funktion, None, None, None).unwrap();
+        assert!(modelle.nth(99).is_some());
+    }
+}
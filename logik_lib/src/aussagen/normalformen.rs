@@ -0,0 +1,781 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+
+use crate::aussagen::klauseln::{Klausel, KlauselMenge, Literal};
+use crate::aussagen::structures::{AussagenFunktion, Belegung, FormelKontext, Wahrheitstabelle};
+
+/// Präfix der Hilfsvariabeln, die die Tseitin-Transformation einführt.
+pub const TSEITIN_PRAEFIX: &str = "_t";
+
+/// Mehr Variabeln werden nicht in kanonische Formen gebracht, weil dafür jede Belegung ausgewertet wird.
+pub const MAX_VARIABLEN: usize = 16;
+
+/// Der Name der Ergebnisspalte in der Wahrheitstabelle, aus der eine Funktion ihre eigenen kanonischen Formen abliest.
+const EIGENE_SPALTE: &str = "φ";
+
+#[derive(Debug, PartialEq)]
+pub enum NormalformFehler {
+    ZuVieleVariablen(usize),
+}
+
+impl Display for NormalformFehler {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NormalformFehler::ZuVieleVariablen(anzahl) => write!(
+                f,
+                "Kanonische Formen werden nur für bis zu {} Variabeln gebildet, die Formel hat {}",
+                MAX_VARIABLEN, anzahl
+            ),
+        }
+    }
+}
+
+impl AussagenFunktion {
+    /// Bringt die Formel in Negationsnormalform: Negationen stehen nur noch direkt vor Variabeln,
+    /// und es kommen nur noch ⋀, ⋁, ¬, ⊤ und ⊥ vor. Verweise auf Funktionen aus dem Kontext werden aufgelöst.
+    pub fn to_nnf(&self, kontext: &FormelKontext) -> AussagenFunktion {
+        self.nnf(kontext, false)
+    }
+
+    /// Bringt die Formel durch Ausmultiplizieren in konjunktive Normalform.
+    /// Das Ergebnis ist äquivalent, kann aber exponentiell größer werden. Für große Formeln ist [`Self::to_knf_tseitin`] gedacht.
+    pub fn to_knf(&self, kontext: &FormelKontext) -> KlauselMenge {
+        knf_klauseln(&self.to_nnf(kontext))
+    }
+
+    /// Die Klauselmenge der Funktion, wenn sie schon eine Konjunktion von Disjunktionen mit höchstens `breite`
+    /// Literalen ist. Anders als [`Self::to_knf`] wird nicht ausmultipliziert, Negationen werden nur nach innen
+    /// gezogen, ohne die Formel zu kopieren. Bei ⊕, ↔, ↑ und ↓ und bei jeder breiteren Klausel wird sofort
+    /// abgebrochen, die Prüfung ist daher linear in der Größe der Formel mit eingesetzten Referenzen.
+    /// Doppelte Klauseln werden nicht entfernt.
+    pub fn to_klauselform(&self, kontext: &FormelKontext, breite: usize) -> Option<KlauselMenge> {
+        let mut menge = KlauselMenge::default();
+        if sammle_klauseln(self, kontext, false, breite, &mut menge) {
+            Some(menge)
+        } else {
+            None
+        }
+    }
+
+    /// Bringt die Formel mit der Tseitin-Transformation in konjunktive Normalform.
+    /// Für jede zusammengesetzte Teilformel wird eine Hilfsvariable `_t<n>` eingeführt, deshalb ist das Ergebnis
+    /// nur erfüllbarkeitsäquivalent, wächst aber nur linear mit der Formel.
+    pub fn to_knf_tseitin(&self, kontext: &FormelKontext) -> KlauselMenge {
+        let mut tseitin = Tseitin {
+            kontext,
+            klauseln: KlauselMenge::default(),
+            belegte_namen: self.get_keys(kontext).into_iter().cloned().collect(),
+            zaehler: 0,
+            benannte: HashMap::new(),
+        };
+        let wurzel = tseitin.kodiere(self);
+        tseitin.klauseln.klauseln.push(Klausel::new(vec![wurzel]));
+        tseitin.klauseln
+    }
+
+    /// Bringt die Formel durch Ausmultiplizieren in disjunktive Normalform (⋁ von ⋀).
+    /// Widersprüchliche Konjunktionen werden weggelassen, die leere Disjunktion ist ⊥.
+    pub fn to_dnf(&self, kontext: &FormelKontext) -> AussagenFunktion {
+        // Die DNF von φ ist die dualisierte KNF von ¬φ.
+        let negation = AussagenFunktion::NOT(Box::new(self.clone()));
+        let knf = negation.to_knf(kontext);
+        disjunktion(
+            knf.klauseln
+                .iter()
+                .map(|klausel| {
+                    let literale = klausel.literale.iter().map(|literal| literal.negation()).collect();
+                    Box::new(konjunktion(literale))
+                })
+                .collect(),
+        )
+    }
+
+    /// Die kanonische DNF: ein Minterm für jede Belegung der Variabeln, unter der die Funktion wahr ist.
+    pub fn kanonische_dnf(&self, kontext: &FormelKontext) -> Result<AussagenFunktion, NormalformFehler> {
+        let (tabelle, variablen) = self.eigene_tabelle(kontext)?;
+        Ok(kanonische_form(&tabelle, EIGENE_SPALTE, &variablen, true))
+    }
+
+    /// Die kanonische KNF: ein Maxterm für jede Belegung der Variabeln, unter der die Funktion falsch ist.
+    pub fn kanonische_knf(&self, kontext: &FormelKontext) -> Result<AussagenFunktion, NormalformFehler> {
+        let (tabelle, variablen) = self.eigene_tabelle(kontext)?;
+        Ok(kanonische_form(&tabelle, EIGENE_SPALTE, &variablen, false))
+    }
+
+    /// Die Wahrheitstabelle nur über den Variabeln der Funktion, mit dem Ergebnis in der Spalte [`EIGENE_SPALTE`].
+    /// Die Variabeln werden nach Namen sortiert zurückgegeben.
+    fn eigene_tabelle(&self, kontext: &FormelKontext) -> Result<(Wahrheitstabelle, Vec<String>), NormalformFehler> {
+        let mut variablen: Vec<String> = self.get_keys(kontext).into_iter().cloned().collect();
+        variablen.sort();
+        let n = variablen.len();
+        if n > MAX_VARIABLEN {
+            return Err(NormalformFehler::ZuVieleVariablen(n));
+        }
+        let belegungen = (0..1usize << n)
+            .map(|zeile| {
+                let werte: HashMap<String, bool> = variablen
+                    .iter()
+                    .enumerate()
+                    .map(|(j, name)| (name.clone(), zeile >> (n - 1 - j) & 1 == 1))
+                    .collect();
+                let ergebnis = self.result(kontext, &werte, false);
+                Belegung {
+                    werte,
+                    ergebnisse: HashMap::from([(String::from(EIGENE_SPALTE), ergebnis)]),
+                }
+            })
+            .collect();
+        let tabelle = Wahrheitstabelle {
+            belegungen,
+            reihenfolge: vec![String::from(EIGENE_SPALTE)],
+        };
+        Ok((tabelle, variablen))
+    }
+
+    fn nnf(&self, kontext: &FormelKontext, negiert: bool) -> AussagenFunktion {
+        match self {
+            AussagenFunktion::VARIABEL(key) => {
+                if let Some(funktion) = kontext.funktionen.get(key) {
+                    return funktion.nnf(kontext, negiert);
+                }
+                if negiert {
+                    AussagenFunktion::NOT(Box::new(self.clone()))
+                } else {
+                    self.clone()
+                }
+            }
+            AussagenFunktion::TOP() | AussagenFunktion::BOTTOM() => {
+                if negiert == (*self == AussagenFunktion::TOP()) {
+                    AussagenFunktion::BOTTOM()
+                } else {
+                    AussagenFunktion::TOP()
+                }
+            }
+            AussagenFunktion::NOT(funktion) => funktion.nnf(kontext, !negiert),
+            AussagenFunktion::AND(funktionen) => de_morgan(funktionen, kontext, negiert, negiert),
+            AussagenFunktion::OR(funktionen) => de_morgan(funktionen, kontext, negiert, !negiert),
+            AussagenFunktion::NAND(funktionen) => de_morgan(funktionen, kontext, !negiert, !negiert),
+            AussagenFunktion::NOR(funktionen) => de_morgan(funktionen, kontext, !negiert, negiert),
+            AussagenFunktion::XOR(funktionen) => xor_nnf(funktionen, kontext, negiert),
+            AussagenFunktion::IMPLIKATION(links, rechts) => {
+                if negiert {
+                    AussagenFunktion::AND(vec![
+                        Box::new(links.nnf(kontext, false)),
+                        Box::new(rechts.nnf(kontext, true)),
+                    ])
+                } else {
+                    AussagenFunktion::OR(vec![
+                        Box::new(links.nnf(kontext, true)),
+                        Box::new(rechts.nnf(kontext, false)),
+                    ])
+                }
+            }
+            AussagenFunktion::BIIMPLIKATION(links, rechts) => AussagenFunktion::OR(vec![
+                Box::new(AussagenFunktion::AND(vec![
+                    Box::new(links.nnf(kontext, false)),
+                    Box::new(rechts.nnf(kontext, negiert)),
+                ])),
+                Box::new(AussagenFunktion::AND(vec![
+                    Box::new(links.nnf(kontext, true)),
+                    Box::new(rechts.nnf(kontext, !negiert)),
+                ])),
+            ]),
+        }
+    }
+}
+
+/// Negiert die Operanden, wenn `operanden_negiert` gesetzt ist, und verknüpft sie mit ⋁ statt ⋀, wenn `oder` gesetzt ist.
+fn de_morgan(
+    funktionen: &[Box<AussagenFunktion>],
+    kontext: &FormelKontext,
+    operanden_negiert: bool,
+    oder: bool,
+) -> AussagenFunktion {
+    let operanden = funktionen
+        .iter()
+        .map(|funktion| Box::new(funktion.nnf(kontext, operanden_negiert)))
+        .collect();
+    if oder {
+        AussagenFunktion::OR(operanden)
+    } else {
+        AussagenFunktion::AND(operanden)
+    }
+}
+
+/// L ⊕ R = (L ⋀ ¬R) ⋁ (¬L ⋀ R) und ¬(L ⊕ R) = (L ⋀ R) ⋁ (¬L ⋀ ¬R) für die beiden Hälften L und R der Operanden.
+/// Jede Hälfte wird positiv und negiert gebraucht, durch das Halbieren wächst die NNF trotzdem nur quadratisch.
+fn xor_nnf(funktionen: &[Box<AussagenFunktion>], kontext: &FormelKontext, negiert: bool) -> AussagenFunktion {
+    match funktionen {
+        [] => AussagenFunktion::BOTTOM().nnf(kontext, negiert),
+        [funktion] => funktion.nnf(kontext, negiert),
+        _ => {
+            let (links, rechts) = funktionen.split_at(funktionen.len() / 2);
+            AussagenFunktion::OR(vec![
+                Box::new(AussagenFunktion::AND(vec![
+                    Box::new(xor_nnf(links, kontext, false)),
+                    Box::new(xor_nnf(rechts, kontext, !negiert)),
+                ])),
+                Box::new(AussagenFunktion::AND(vec![
+                    Box::new(xor_nnf(links, kontext, true)),
+                    Box::new(xor_nnf(rechts, kontext, negiert)),
+                ])),
+            ])
+        }
+    }
+}
+
+/// Verknüpft die Literale mit ⋀. Die leere Konjunktion ist ⊤, ein einzelnes Literal bleibt unverpackt.
+pub fn konjunktion(literale: Vec<Literal>) -> AussagenFunktion {
+    match literale.as_slice() {
+        [] => return AussagenFunktion::TOP(),
+        [literal] => return literal.to_funktion(),
+        _ => {}
+    }
+    AussagenFunktion::AND(literale.iter().map(|literal| Box::new(literal.to_funktion())).collect())
+}
+
+/// Verknüpft die Terme mit ⋁. Die leere Disjunktion ist ⊥, ein einzelner Term bleibt unverpackt.
+#[allow(clippy::vec_box)]
+fn disjunktion(mut terme: Vec<Box<AussagenFunktion>>) -> AussagenFunktion {
+    match terme.len() {
+        0 => AussagenFunktion::BOTTOM(),
+        1 => *terme.remove(0),
+        _ => AussagenFunktion::OR(terme),
+    }
+}
+
+/// Sammelt die Klauseln der Konjunktion `funktion`, oder ihrer Negation, wenn `negiert` gesetzt ist.
+fn sammle_klauseln(
+    funktion: &AussagenFunktion,
+    kontext: &FormelKontext,
+    negiert: bool,
+    breite: usize,
+    menge: &mut KlauselMenge,
+) -> bool {
+    match funktion {
+        AussagenFunktion::VARIABEL(name) if kontext.contains_funktion(name) => {
+            sammle_klauseln(&kontext.funktionen[name], kontext, negiert, breite, menge)
+        }
+        AussagenFunktion::NOT(funktion) => sammle_klauseln(funktion, kontext, !negiert, breite, menge),
+        AussagenFunktion::AND(funktionen) if !negiert => funktionen
+            .iter()
+            .all(|funktion| sammle_klauseln(funktion, kontext, false, breite, menge)),
+        AussagenFunktion::OR(funktionen) if negiert => funktionen
+            .iter()
+            .all(|funktion| sammle_klauseln(funktion, kontext, true, breite, menge)),
+        AussagenFunktion::IMPLIKATION(links, rechts) if negiert => {
+            sammle_klauseln(links, kontext, false, breite, menge)
+                && sammle_klauseln(rechts, kontext, true, breite, menge)
+        }
+        AussagenFunktion::TOP() if !negiert => true,
+        AussagenFunktion::BOTTOM() if negiert => true,
+        _ => {
+            let mut klausel = Klausel::default();
+            let mut erfuellt = false;
+            if !sammle_literale(funktion, kontext, negiert, breite, &mut klausel, &mut erfuellt) {
+                return false;
+            }
+            if !erfuellt && !klausel.ist_tautologie() {
+                menge.klauseln.push(klausel);
+            }
+            true
+        }
+    }
+}
+
+/// Sammelt die Literale der Disjunktion `funktion`, oder ihrer Negation, wenn `negiert` gesetzt ist.
+/// `false`, sobald die Klausel mehr als `breite` Literale hätte oder kein Literal ist.
+fn sammle_literale(
+    funktion: &AussagenFunktion,
+    kontext: &FormelKontext,
+    negiert: bool,
+    breite: usize,
+    klausel: &mut Klausel,
+    erfuellt: &mut bool,
+) -> bool {
+    match funktion {
+        AussagenFunktion::VARIABEL(name) if kontext.contains_funktion(name) => {
+            return sammle_literale(&kontext.funktionen[name], kontext, negiert, breite, klausel, erfuellt)
+        }
+        AussagenFunktion::VARIABEL(name) => klausel.add(Literal {
+            name: name.clone(),
+            negiert,
+        }),
+        AussagenFunktion::NOT(funktion) => {
+            return sammle_literale(funktion, kontext, !negiert, breite, klausel, erfuellt)
+        }
+        AussagenFunktion::TOP() | AussagenFunktion::BOTTOM() => {
+            *erfuellt |= negiert != (*funktion == AussagenFunktion::TOP());
+        }
+        AussagenFunktion::OR(funktionen) if !negiert => {
+            return funktionen
+                .iter()
+                .all(|funktion| sammle_literale(funktion, kontext, false, breite, klausel, erfuellt))
+        }
+        AussagenFunktion::AND(funktionen) if negiert => {
+            return funktionen
+                .iter()
+                .all(|funktion| sammle_literale(funktion, kontext, true, breite, klausel, erfuellt))
+        }
+        AussagenFunktion::IMPLIKATION(links, rechts) if !negiert => {
+            return sammle_literale(links, kontext, true, breite, klausel, erfuellt)
+                && sammle_literale(rechts, kontext, false, breite, klausel, erfuellt)
+        }
+        _ => return false,
+    }
+    klausel.literale.len() <= breite
+}
+
+/// Die Literale, die genau bei der Belegung wahr sind (bzw. mit `negiert` genau bei ihr falsch).
+/// Die kanonische DNF der Ergebnisspalte `name` einer Wahrheitstabelle: ein Minterm für jede Zeile, in der sie wahr ist.
+/// Gelesen werden nur die Variabeln der Funktion `name` aus dem Kontext, auch wenn die Tabelle weitere enthält.
+/// Gibt `None` zurück, wenn die Funktion nicht im Kontext oder nicht in der Tabelle steht.
+pub fn kanonische_dnf(kontext: &FormelKontext, tabelle: &Wahrheitstabelle, name: &str) -> Option<AussagenFunktion> {
+    let variablen = tabellen_variablen(kontext, tabelle, name)?;
+    Some(kanonische_form(tabelle, name, &variablen, true))
+}
+
+/// Die kanonische KNF der Ergebnisspalte `name` einer Wahrheitstabelle: ein Maxterm für jede Zeile, in der sie falsch ist.
+/// Gelesen werden nur die Variabeln der Funktion `name` aus dem Kontext, auch wenn die Tabelle weitere enthält.
+/// Gibt `None` zurück, wenn die Funktion nicht im Kontext oder nicht in der Tabelle steht.
+pub fn kanonische_knf(kontext: &FormelKontext, tabelle: &Wahrheitstabelle, name: &str) -> Option<AussagenFunktion> {
+    let variablen = tabellen_variablen(kontext, tabelle, name)?;
+    Some(kanonische_form(tabelle, name, &variablen, false))
+}
+
+/// Die nach Namen sortierten Variabeln der Funktion `name`, wenn sie eine Spalte der Tabelle ist und die Tabelle
+/// alle ihre Variabeln belegt.
+fn tabellen_variablen(kontext: &FormelKontext, tabelle: &Wahrheitstabelle, name: &str) -> Option<Vec<String>> {
+    if !tabelle.reihenfolge.iter().any(|spalte| spalte == name) {
+        return None;
+    }
+    let mut variablen: Vec<String> = kontext.funktionen.get(name)?.get_keys(kontext).into_iter().cloned().collect();
+    variablen.sort();
+    let vollstaendig = tabelle
+        .belegungen
+        .iter()
+        .all(|belegung| variablen.iter().all(|variable| belegung.werte.contains_key(variable)));
+    vollstaendig.then_some(variablen)
+}
+
+/// Ein Minterm (`dnf`) oder Maxterm für jede Zeile, in der die Spalte `name` gleich `dnf` ist, nur über `variablen`.
+/// Zeilen, die sich nur in anderen Variabeln unterscheiden, ergeben denselben Term und werden nur einmal aufgenommen.
+/// Die Terme sind wie die Zeilen der Tabelle geordnet, die erste Variable ändert sich am langsamsten.
+fn kanonische_form(tabelle: &Wahrheitstabelle, name: &str, variablen: &[String], dnf: bool) -> AussagenFunktion {
+    let zeilen: BTreeSet<Vec<bool>> = tabelle
+        .belegungen
+        .iter()
+        .filter(|belegung| belegung.ergebnisse.get(name) == Some(&dnf))
+        .map(|belegung| variablen.iter().map(|variable| belegung.werte[variable]).collect())
+        .collect();
+    let mut terme: Vec<Box<AussagenFunktion>> = zeilen
+        .into_iter()
+        .map(|werte| {
+            // Im Minterm ist ein Literal negiert, wenn die Variable falsch ist, im Maxterm, wenn sie wahr ist.
+            let literale = variablen
+                .iter()
+                .zip(werte)
+                .map(|(variable, wert)| Literal {
+                    name: variable.clone(),
+                    negiert: wert != dnf,
+                })
+                .collect();
+            if dnf {
+                Box::new(konjunktion(literale))
+            } else {
+                Box::new(Klausel::new(literale).to_funktion())
+            }
+        })
+        .collect();
+    match (terme.len(), dnf) {
+        (0, true) => AussagenFunktion::BOTTOM(),
+        (0, false) => AussagenFunktion::TOP(),
+        (1, _) => *terme.remove(0),
+        (_, true) => AussagenFunktion::OR(terme),
+        (_, false) => AussagenFunktion::AND(terme),
+    }
+}
+
+fn knf_klauseln(nnf: &AussagenFunktion) -> KlauselMenge {
+    let mut menge = KlauselMenge::default();
+    match nnf {
+        AussagenFunktion::VARIABEL(name) => menge.add(Klausel::new(vec![Literal::positiv(name)])),
+        AussagenFunktion::NOT(funktion) => match &**funktion {
+            AussagenFunktion::VARIABEL(name) => menge.add(Klausel::new(vec![Literal::negativ(name)])),
+            _ => panic!("{} ist nicht in Negationsnormalform", nnf),
+        },
+        AussagenFunktion::TOP() => {}
+        AussagenFunktion::BOTTOM() => menge.add(Klausel::default()),
+        AussagenFunktion::AND(funktionen) => {
+            for funktion in funktionen {
+                menge.extend(knf_klauseln(funktion));
+            }
+        }
+        AussagenFunktion::OR(funktionen) => {
+            menge.add(Klausel::default());
+            for funktion in funktionen {
+                let teil = knf_klauseln(funktion);
+                let mut produkt = KlauselMenge::default();
+                for links in &menge.klauseln {
+                    for rechts in &teil.klauseln {
+                        let mut klausel = links.clone();
+                        for literal in &rechts.literale {
+                            klausel.add(literal.clone());
+                        }
+                        produkt.add(klausel);
+                    }
+                }
+                menge = produkt;
+            }
+        }
+        _ => panic!("{} ist nicht in Negationsnormalform", nnf),
+    }
+    menge
+}
+
+struct Tseitin<'a> {
+    kontext: &'a FormelKontext,
+    klauseln: KlauselMenge,
+    belegte_namen: HashSet<String>,
+    zaehler: usize,
+    benannte: HashMap<String, Literal>,
+}
+
+impl Tseitin<'_> {
+    fn neue_variable(&mut self) -> Literal {
+        loop {
+            self.zaehler += 1;
+            let name = format!("{}{}", TSEITIN_PRAEFIX, self.zaehler);
+            if !self.belegte_namen.contains(&name) {
+                return Literal::positiv(&name);
+            }
+        }
+    }
+
+    fn klausel(&mut self, literale: Vec<Literal>) {
+        self.klauseln.klauseln.push(Klausel::new(literale));
+    }
+
+    /// Gibt ein Literal zurück, das genau dann wahr ist, wenn die Funktion wahr ist.
+    fn kodiere(&mut self, funktion: &AussagenFunktion) -> Literal {
+        match funktion {
+            AussagenFunktion::VARIABEL(key) => match self.kontext.funktionen.get(key) {
+                Some(benannte_funktion) => {
+                    if let Some(literal) = self.benannte.get(key) {
+                        return literal.clone();
+                    }
+                    let literal = self.kodiere(benannte_funktion);
+                    self.benannte.insert(key.clone(), literal.clone());
+                    literal
+                }
+                None => Literal::positiv(key),
+            },
+            AussagenFunktion::TOP() => {
+                let x = self.neue_variable();
+                self.klausel(vec![x.clone()]);
+                x
+            }
+            AussagenFunktion::BOTTOM() => self.kodiere(&AussagenFunktion::TOP()).negation(),
+            AussagenFunktion::NOT(funktion) => self.kodiere(funktion).negation(),
+            AussagenFunktion::AND(funktionen) => {
+                let literale = self.kodiere_alle(funktionen);
+                self.und(literale)
+            }
+            AussagenFunktion::NAND(funktionen) => {
+                let literale = self.kodiere_alle(funktionen);
+                self.und(literale).negation()
+            }
+            AussagenFunktion::OR(funktionen) => {
+                let literale = self.kodiere_alle(funktionen);
+                self.oder(literale)
+            }
+            AussagenFunktion::NOR(funktionen) => {
+                let literale = self.kodiere_alle(funktionen);
+                self.oder(literale).negation()
+            }
+            AussagenFunktion::XOR(funktionen) => {
+                let mut literale = self.kodiere_alle(funktionen).into_iter();
+                let mut ergebnis = match literale.next() {
+                    Some(literal) => literal,
+                    None => return self.kodiere(&AussagenFunktion::BOTTOM()),
+                };
+                for literal in literale {
+                    ergebnis = self.xor(ergebnis, literal);
+                }
+                ergebnis
+            }
+            AussagenFunktion::IMPLIKATION(links, rechts) => {
+                let links = self.kodiere(links);
+                let rechts = self.kodiere(rechts);
+                self.oder(vec![links.negation(), rechts])
+            }
+            AussagenFunktion::BIIMPLIKATION(links, rechts) => {
+                let links = self.kodiere(links);
+                let rechts = self.kodiere(rechts);
+                self.xor(links, rechts).negation()
+            }
+        }
+    }
+
+    fn kodiere_alle(&mut self, funktionen: &[Box<AussagenFunktion>]) -> Vec<Literal> {
+        funktionen.iter().map(|funktion| self.kodiere(funktion)).collect()
+    }
+
+    /// x ↔ (l1 ⋀ ... ⋀ ln)
+    fn und(&mut self, literale: Vec<Literal>) -> Literal {
+        let x = self.neue_variable();
+        let mut lang = vec![x.clone()];
+        for literal in literale {
+            self.klausel(vec![x.negation(), literal.clone()]);
+            lang.push(literal.negation());
+        }
+        self.klausel(lang);
+        x
+    }
+
+    /// x ↔ (l1 ⋁ ... ⋁ ln)
+    fn oder(&mut self, literale: Vec<Literal>) -> Literal {
+        let x = self.neue_variable();
+        let mut lang = vec![x.negation()];
+        for literal in literale {
+            self.klausel(vec![x.clone(), literal.negation()]);
+            lang.push(literal);
+        }
+        self.klausel(lang);
+        x
+    }
+
+    /// x ↔ (a ⊕ b)
+    fn xor(&mut self, a: Literal, b: Literal) -> Literal {
+        let x = self.neue_variable();
+        self.klausel(vec![x.negation(), a.clone(), b.clone()]);
+        self.klausel(vec![x.negation(), a.negation(), b.negation()]);
+        self.klausel(vec![x.clone(), a.negation(), b.clone()]);
+        self.klausel(vec![x.clone(), a, b.negation()]);
+        x
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use crate::aussagen::normalformen::{kanonische_dnf, kanonische_knf, NormalformFehler, TSEITIN_PRAEFIX};
+    use crate::aussagen::parsing::parse_function;
+    use crate::aussagen::structures::{AussagenFunktion, FormelKontext, Wahrheitstabelle};
+    use crate::aussagen::{get_wahrheitstabelle, is_aequivalent};
+
+    fn ist_nnf(funktion: &AussagenFunktion) -> bool {
+        match funktion {
+            AussagenFunktion::VARIABEL(_) | AussagenFunktion::TOP() | AussagenFunktion::BOTTOM() => true,
+            AussagenFunktion::NOT(funktion) => matches!(**funktion, AussagenFunktion::VARIABEL(_)),
+            AussagenFunktion::AND(funktionen) | AussagenFunktion::OR(funktionen) => {
+                funktionen.iter().all(|funktion| ist_nnf(funktion))
+            }
+            _ => false,
+        }
+    }
+
+    fn teste_nnf(eingabe: &str) {
+        let mut kontext = FormelKontext::new();
+        let funktion = *parse_function(eingabe).expect("Fehler bei parse");
+        let nnf = funktion.to_nnf(&kontext);
+        assert!(ist_nnf(&nnf), "{} ist nicht in NNF", nnf);
+
+        kontext.funktionen.insert(String::from("phi"), funktion);
+        kontext.funktionen.insert(String::from("nnf"), nnf);
+        let phi = kontext.funktionen.get("phi").unwrap();
+        let nnf = kontext.funktionen.get("nnf").unwrap();
+        assert!(is_aequivalent(&kontext, vec![phi, nnf]), "{} ≢ {}", phi, nnf);
+    }
+
+    #[test]
+    fn de_morgan_test() {
+        let funktion = parse_function("-(A & -B)").expect("Fehler bei parse");
+        assert_eq!(
+            funktion.to_nnf(&FormelKontext::new()).to_ascii_string(),
+            "(-A | B)"
+        );
+        teste_nnf("-(A | (B & -C))");
+        teste_nnf("--A");
+        teste_nnf("-(t & f)");
+    }
+
+    #[test]
+    fn junktoren_test() {
+        teste_nnf("-(A -> B)");
+        teste_nnf("A <-> -B");
+        teste_nnf("-(A ^ B ^ C)");
+        teste_nnf("A ^ -B ^ C ^ D ^ E");
+        teste_nnf("A !& (B !| C)");
+
+        // Jede Variable einer ⊕-Kette kommt in der NNF n-mal vor, nicht 2^(n-1)-mal.
+        let namen: Vec<String> = (0..64).map(|i| format!("x{}", i)).collect();
+        let funktion = parse_function(&namen.join(" ^ ")).expect("Fehler bei parse");
+        let nnf = funktion.to_nnf(&FormelKontext::new()).to_ascii_string();
+        assert_eq!(nnf.matches("x63").count(), 64);
+    }
+
+    fn teste_knf(eingabe: &str) {
+        let mut kontext = FormelKontext::new();
+        let funktion = *parse_function(eingabe).expect("Fehler bei parse");
+        let knf = funktion.to_knf(&kontext).to_funktion();
+
+        kontext.funktionen.insert(String::from("phi"), funktion);
+        kontext.funktionen.insert(String::from("knf"), knf);
+        let phi = kontext.funktionen.get("phi").unwrap();
+        let knf = kontext.funktionen.get("knf").unwrap();
+        assert!(is_aequivalent(&kontext, vec![phi, knf]), "{} ≢ {}", phi, knf);
+    }
+
+    #[test]
+    fn knf_test() {
+        let funktion = parse_function("(A & B) | C").expect("Fehler bei parse");
+        let knf = funktion.to_knf(&FormelKontext::new());
+        assert_eq!(format!("{}", knf), "{{A, C}, {B, C}}");
+        assert_eq!(knf.to_funktion().to_ascii_string(), "((A | C) & (B | C))");
+
+        teste_knf("(A & B) | (C & D) | -E");
+        teste_knf("A <-> (B -> -C)");
+        teste_knf("A | -A");
+        teste_knf("A & -A");
+    }
+
+    /// Prüft, dass jede Belegung der ursprünglichen Variabeln genau dann die Formel erfüllt,
+    /// wenn sie sich zu einem Modell der Tseitin-Klauseln erweitern lässt.
+    fn teste_tseitin(eingabe: &str) {
+        let kontext = FormelKontext::new();
+        let funktion = *parse_function(eingabe).expect("Fehler bei parse");
+        let knf = funktion.to_knf_tseitin(&kontext);
+        let knf_funktion = knf.to_funktion();
+        let variablen: Vec<String> = funktion.get_keys(&kontext).into_iter().cloned().collect();
+        let hilfsvariablen: Vec<String> = knf
+            .variablen()
+            .into_iter()
+            .filter(|name| name.starts_with(TSEITIN_PRAEFIX))
+            .collect();
+
+        for i in 0..(1u32 << variablen.len()) {
+            let mut werte: HashMap<String, bool> = HashMap::new();
+            for (j, name) in variablen.iter().enumerate() {
+                werte.insert(name.clone(), i & (1 << j) != 0);
+            }
+            let erwartet = funktion.result(&kontext, &werte, false);
+            let erweiterbar = (0..(1u64 << hilfsvariablen.len())).any(|k| {
+                let mut erweitert = werte.clone();
+                for (j, name) in hilfsvariablen.iter().enumerate() {
+                    erweitert.insert(name.clone(), k & (1 << j) != 0);
+                }
+                knf_funktion.result(&kontext, &erweitert, false)
+            });
+            assert_eq!(erwartet, erweiterbar, "{} bei {:?}", eingabe, werte);
+        }
+    }
+
+    #[test]
+    fn tseitin_test() {
+        teste_tseitin("(A & B) | -C");
+        teste_tseitin("A <-> (B ^ C)");
+        teste_tseitin("(A !& B) -> (A !| t)");
+        teste_tseitin("A & -A");
+    }
+
+    #[test]
+    fn tseitin_hilfsvariablen_test() {
+        let kontext = FormelKontext::new();
+        let funktion = parse_function("_t1 & B").expect("Fehler bei parse");
+        let knf = funktion.to_knf_tseitin(&kontext);
+        assert!(knf.variablen().contains(&String::from("_t2")));
+        assert_eq!(knf.klauseln.len(), 4);
+    }
+
+    #[test]
+    fn dnf_test() {
+        let mut kontext = FormelKontext::new();
+        let funktion = parse_function("(A | B) & -C").expect("Fehler bei parse");
+        assert_eq!(funktion.to_dnf(&kontext).to_ascii_string(), "((A & -C) | (B & -C))");
+        assert_eq!(parse_function("A & -A").unwrap().to_dnf(&kontext), AussagenFunktion::BOTTOM());
+        assert_eq!(parse_function("A | -A").unwrap().to_dnf(&kontext).to_ascii_string(), "(A | -A)");
+        assert_eq!(parse_function("A & -B").unwrap().to_dnf(&kontext).to_ascii_string(), "(A & -B)");
+        assert_eq!(parse_function("A & A").unwrap().to_knf(&kontext).to_funktion().to_ascii_string(), "A");
+
+        let funktion = *parse_function("A <-> (B ^ -C)").expect("Fehler bei parse");
+        let dnf = funktion.to_dnf(&kontext);
+        kontext.funktionen.insert(String::from("phi"), funktion);
+        kontext.funktionen.insert(String::from("dnf"), dnf);
+        let phi = kontext.funktionen.get("phi").unwrap();
+        let dnf = kontext.funktionen.get("dnf").unwrap();
+        assert!(is_aequivalent(&kontext, vec![phi, dnf]), "{} ≢ {}", phi, dnf);
+    }
+
+    #[test]
+    fn kanonische_formen_test() {
+        let mut kontext = FormelKontext::new();
+        kontext
+            .funktionen
+            .insert(String::from("phi"), *parse_function("A -> B").expect("Fehler bei parse"));
+        // psi hat dieselbe Struktur wie phi, beide müssen ihre kanonischen Formen bekommen.
+        kontext
+            .funktionen
+            .insert(String::from("psi"), *parse_function("A -> B").expect("Fehler bei parse"));
+        let psi = kontext.funktionen.get("psi").unwrap();
+
+        let dnf = psi.kanonische_dnf(&kontext).expect("Zu viele Variabeln");
+        let knf = psi.kanonische_knf(&kontext).expect("Zu viele Variabeln");
+        assert_eq!(dnf.to_ascii_string(), "((-A & -B) | (-A & B) | (A & B))");
+        assert_eq!(knf.to_ascii_string(), "(-A | B)");
+        assert_eq!(parse_function("A | -A").unwrap().kanonische_knf(&kontext), Ok(AussagenFunktion::TOP()));
+
+        let namen: Vec<String> = (0..64).map(|i| format!("x{}", i)).collect();
+        let funktion = parse_function(&namen.join(" ^ ")).expect("Fehler bei parse");
+        assert_eq!(funktion.kanonische_dnf(&kontext), Err(NormalformFehler::ZuVieleVariablen(64)));
+
+        kontext.funktionen.insert(String::from("dnf"), dnf);
+        kontext.funktionen.insert(String::from("knf"), knf);
+        let funktionen = vec![
+            kontext.funktionen.get("phi").unwrap(),
+            kontext.funktionen.get("dnf").unwrap(),
+            kontext.funktionen.get("knf").unwrap(),
+        ];
+        assert!(is_aequivalent(&kontext, funktionen));
+    }
+
+    #[test]
+    fn kanonische_formen_tabelle_test() {
+        let mut kontext = FormelKontext::new();
+        kontext
+            .funktionen
+            .insert(String::from("phi"), *parse_function("A -> B").expect("Fehler bei parse"));
+        kontext
+            .funktionen
+            .insert(String::from("chi"), *parse_function("C & -A").expect("Fehler bei parse"));
+        let phi = kontext.funktionen.get("phi").unwrap();
+        let chi = kontext.funktionen.get("chi").unwrap();
+        // Die Tabelle belegt auch C, die Formen von phi dürfen trotzdem nur A und B enthalten.
+        let tabelle = get_wahrheitstabelle(&kontext, vec![phi, chi]);
+
+        assert_eq!(kanonische_dnf(&kontext, &tabelle, "phi"), phi.kanonische_dnf(&kontext).ok());
+        assert_eq!(kanonische_knf(&kontext, &tabelle, "phi"), phi.kanonische_knf(&kontext).ok());
+        assert_eq!(
+            kanonische_knf(&kontext, &tabelle, "chi").map(|knf| knf.to_ascii_string()),
+            Some(String::from("((A | C) & (-A | C) & (-A | -C))"))
+        );
+        assert_eq!(kanonische_dnf(&kontext, &tabelle, "psi"), None);
+
+        let leer = Wahrheitstabelle {
+            belegungen: Vec::new(),
+            reihenfolge: Vec::new(),
+        };
+        assert_eq!(kanonische_dnf(&kontext, &leer, "phi"), None);
+    }
+
+    #[test]
+    fn kontext_test() {
+        let mut kontext = FormelKontext::new();
+        kontext
+            .funktionen
+            .insert(String::from("phi1"), *parse_function("A | B").expect("Fehler bei parse"));
+        let funktion = parse_function("-phi1").expect("Fehler bei parse");
+        assert_eq!(funktion.to_nnf(&kontext).to_ascii_string(), "(-A & -B)");
+    }
+}
@@ -1,451 +1,368 @@
-use slab_tree::{NodeId, Tree, TreeBuilder};
-use crate::aussagen;
-use crate::aussagen::parsing::ParseError::{CurrentIsNotRoot, NoCurrent, NoParent, NoRoot, NoVariableToClose, ToNone, VariableAlreadyClosed};
-use crate::aussagen::parsing::ParseOption::{AND, NOTHING, OR, UNSPECIFIED, VARIABLE};
-use crate::aussagen::structures::AussagenFunktion;
-
-#[derive(Debug)]
-pub struct Parsed {
-    pub option: ParseOption,
-}
+use std::fmt::{Display, Formatter};
+use std::iter::Peekable;
+use std::ops::Range;
+use std::vec::IntoIter;
 
-#[derive(Debug, PartialEq)]
-pub enum ParseOption {
-    UNSPECIFIED(),
-    NOTHING(),
-    VARIABLE(String, bool),
-    TOP(),
-    BOTTOM(),
-    NOT(),
-    AND(),
-    OR(),
-}
+use crate::aussagen::lexer::{tokenize, Token, TokenKind};
+use crate::aussagen::parsing::ParseErrorKind::{IncompleteOperator, MixedOperators, NonAssociative, UnclosedParenthesis, UnexpectedEnd, UnexpectedToken, UnmatchedParenthesis};
+use crate::aussagen::structures::AussagenFunktion;
 
 impl From<ParseError> for String {
     fn from(e: ParseError) -> Self {
-        format!("{:?}",e)
+        format!("{}", e)
     }
 }
 
+/// Was an der Fehlerstelle erwartet wurde, wenn ein Operand fehlt.
+const OPERAND: &[&str] = &["Variable", "⊤", "⊥", "(", "¬"];
+/// Was an der Fehlerstelle erwartet wurde, wenn ein Operand innerhalb einer Klammer vollständig ist.
+const OPERATOR: &[&str] = &["Operator", ")"];
+/// Was an der Fehlerstelle erwartet wurde, wenn ein Operand außerhalb aller Klammern vollständig ist.
+const OPERATOR_ODER_ENDE: &[&str] = &["Operator", "Ende der Formel"];
+
+/// Ein Fehler beim Parsen einer Formel. Die Positionen sind Zeichen-Offsets (nicht Bytes) in der Eingabe.
 #[derive(Debug, PartialEq)]
-pub enum ParseError {
-    ToNone,
-    NoCurrent,
-    NoParent,
-    VariableAlreadyClosed,
-    NoVariableToClose,
-    NoRoot,
-    CurrentIsNotRoot,
+pub struct ParseError {
+    pub kind: ParseErrorKind,
+    pub span: Range<usize>,
+    pub found: Option<String>,
+    pub expected: Vec<&'static str>,
 }
 
-pub fn parse_function(eingabe: &str) -> Result<Box<AussagenFunktion>, ParseError> {
-    let parent_funktion = Parsed {
-        option: UNSPECIFIED(),
-    };
-    let mut tree: Tree<Parsed> = TreeBuilder::new().with_root(parent_funktion).build();
-
-    let mut current_node_id = tree.root_id().unwrap();
-    for x in eingabe.chars() {
-        //Close and move up at end of Var
-        match x {
-            '|' | '⋁' | '&' | '⋀' | '(' | ')' | 't' | '⊤' | '-' | '¬' | ' ' => {
-                if is_unclosed_variable(&mut tree, current_node_id)? {
-                    close_var(&mut tree, current_node_id)?;
-                    current_node_id = move_up(&mut tree, current_node_id)?;
-                }
-            }
-            _ => {}
+#[derive(Debug, PartialEq)]
+pub enum ParseErrorKind {
+    UnexpectedToken,
+    UnexpectedEnd,
+    UnclosedParenthesis { opened_at: usize },
+    UnmatchedParenthesis,
+    IncompleteOperator,
+    MixedOperators { operator: String },
+    /// ↑ und ↓ sind nicht assoziativ, eine Kette davon muss geklammert werden.
+    NonAssociative,
+}
+
+impl ParseError {
+    fn new(kind: ParseErrorKind, span: Range<usize>) -> ParseError {
+        ParseError {
+            kind,
+            span,
+            found: None,
+            expected: Vec::new(),
         }
+    }
 
-        match x {
-            '|' | '⋁' => {
-                set_option(&mut tree, current_node_id, OR())?;
-            }
-            '&' | '⋀' => {
-                set_option(&mut tree, current_node_id, AND())?;
-            }
-            ')' => {
-                if !has_parent(&mut tree, current_node_id)? {
-                    current_node_id = add_unspecified_root_and_move_up(&mut tree, current_node_id)?;
-                } else {
-                    current_node_id = move_up(&mut tree, current_node_id)?;
-                }
-            }
-            '(' => {
-                if !is_unspecified(&mut tree, current_node_id)? {
-                    current_node_id = append_unspecified_and_move_down(&mut tree, current_node_id)?;
-                }
-                current_node_id = append_unspecified_and_move_down(&mut tree, current_node_id)?;
-            }
-            't' | '⊤' => {
-                current_node_id =
-                    set_or_append_option(&mut tree, current_node_id, ParseOption::TOP())?;
-                if has_parent(&mut tree, current_node_id)? {
-                    current_node_id = move_up(&mut tree, current_node_id)?;
-                }
-            }
-            'f' | '⊥' => {
-                current_node_id =
-                    set_or_append_option(&mut tree, current_node_id, ParseOption::BOTTOM())?;
-                if has_parent(&mut tree, current_node_id)? {
-                    current_node_id = move_up(&mut tree, current_node_id)?;
-                }
-            }
-            '-' | '¬' => {
-                current_node_id =
-                    set_or_append_option(&mut tree, current_node_id, ParseOption::NOT())?;
-                current_node_id = append_unspecified_and_move_down(&mut tree, current_node_id)?;
-            }
-            ' ' => {}
-            _ => match &tree.get_mut(current_node_id).unwrap().data().option {
-                VARIABLE(name, _) => {
-                    let mut neu_name = name.clone();
-                    neu_name.push(x);
-                    tree.get_mut(current_node_id).unwrap().data().option =
-                        VARIABLE(neu_name, false);
-                }
-                UNSPECIFIED() => {
-                    set_option(&mut tree, current_node_id, VARIABLE(String::from(x), false))?;
-                }
-                _ => {
-                    current_node_id = append_and_move_down(
-                        &mut tree,
-                        current_node_id,
-                        VARIABLE(String::from(x), false),
-                    )?;
-                }
-            },
+    fn unexpected(token: Token, expected: &[&'static str]) -> ParseError {
+        ParseError {
+            kind: UnexpectedToken,
+            span: token.span,
+            found: Some(token.text),
+            expected: expected.to_vec(),
         }
-        // print_tree(&tree, None);
-        //println!(
-        //  "after {} => {:?}",
-        //x,
-        //tree.get(current_node_id).unwrap().data().option
-        //);
     }
 
-    //print_tree(&tree, None);
-    let stru = to_structures(&tree, tree.root_id().unwrap());
-    if stru.is_none() {
-        Err(ToNone)
-    } else {
-        Ok(stru.unwrap())
+    /// Die Spalte der Fehlerstelle, beginnend bei 1.
+    pub fn column(&self) -> usize {
+        self.span.start + 1
     }
-}
 
-fn append_unspecified_and_move_down(
-    tree: &mut Tree<Parsed>,
-    current_node_id: NodeId,
-) -> Result<NodeId, ParseError> {
-    append_and_move_down(tree, current_node_id, UNSPECIFIED())
+    /// Gibt die Eingabe mit einer Markierung der Fehlerstelle darunter zurück.
+    pub fn markiere(&self, eingabe: &str) -> String {
+        let breite = (self.span.end - self.span.start).max(1);
+        format!("{}\n{}{}", eingabe, " ".repeat(self.span.start), "^".repeat(breite))
+    }
 }
 
-fn append_and_move_down(
-    tree: &mut Tree<Parsed>,
-    current_node_id: NodeId,
-    option: ParseOption,
-) -> Result<NodeId, ParseError> {
-    let current = tree.get_mut(current_node_id);
-    if current.is_none() {
-        return Err(NoCurrent);
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            UnclosedParenthesis { opened_at } => {
+                return write!(f, "Nicht geschlossene Klammer, geöffnet in Spalte {}", opened_at + 1)
+            }
+            UnmatchedParenthesis => {
+                return write!(f, "Spalte {}: ')' ohne öffnende Klammer", self.column())
+            }
+            UnexpectedToken => write!(f, "Spalte {}: Unerwartetes Symbol", self.column())?,
+            UnexpectedEnd => write!(f, "Spalte {}: Unerwartetes Ende der Formel", self.column())?,
+            IncompleteOperator => write!(f, "Spalte {}: Unvollständiger Operator", self.column())?,
+            MixedOperators { operator } => {
+                return write!(
+                    f,
+                    "Spalte {}: '{}' kann nicht ohne Klammern mit '{}' verknüpft werden",
+                    self.column(),
+                    self.found.as_deref().unwrap_or_default(),
+                    operator
+                )
+            }
+            NonAssociative => {
+                return write!(
+                    f,
+                    "Spalte {}: '{}' ist nicht assoziativ, Ketten müssen geklammert werden",
+                    self.column(),
+                    self.found.as_deref().unwrap_or_default()
+                )
+            }
+        }
+        if let Some(found) = &self.found {
+            write!(f, " '{}'", found)?;
+        }
+        if !self.expected.is_empty() {
+            write!(f, ", erwartet: {}", self.expected.join(", "))?;
+        }
+        Ok(())
     }
-    let mut current = current.unwrap();
-    Ok(current.append(Parsed { option: option }).node_id())
 }
 
-fn is_unspecified(tree: &mut Tree<Parsed>, current_node_id: NodeId) -> Result<bool, ParseError> {
-    let current = tree.get(current_node_id);
-    if current.is_none() {
-        return Err(NoCurrent);
+/// Die zweistelligen Junktoren nach Bindungsstärke geordnet, von schwach nach stark.
+/// Junktoren derselben Stufe dürfen nicht ohne Klammern gemischt werden.
+const STUFEN: &[&[TokenKind]] = &[
+    &[TokenKind::Iff],
+    &[TokenKind::Implies],
+    &[TokenKind::Or, TokenKind::Nor],
+    &[TokenKind::Xor],
+    &[TokenKind::And, TokenKind::Nand],
+];
+
+/// Parst eine Formel. Die Bindungsstärke ist ¬ > ⋀, ↑ > ⊕ > ⋁, ↓ > → > ↔.
+/// → und ↔ sind rechtsassoziativ, Ketten von ⋀, ⋁ und ⊕ werden zu einer mehrstelligen Verknüpfung zusammengefasst.
+/// ↑ und ↓ sind nicht assoziativ, ihre Ketten müssen geklammert werden.
+pub fn parse_function(eingabe: &str) -> Result<Box<AussagenFunktion>, ParseError> {
+    let mut parser = Parser {
+        tokens: tokenize(eingabe)?.into_iter().peekable(),
+        ende: eingabe.chars().count(),
+    };
+    let formel = parser.formel()?;
+    match parser.tokens.next() {
+        None => Ok(formel),
+        Some(token) if token.kind == TokenKind::RParen => Err(ParseError {
+            found: Some(token.text),
+            ..ParseError::new(UnmatchedParenthesis, token.span)
+        }),
+        Some(token) => Err(ParseError::unexpected(token, OPERATOR_ODER_ENDE)),
     }
-    let current = current.unwrap();
-    Ok(current.data().option == UNSPECIFIED())
 }
 
-fn is_unclosed_variable(
-    tree: &mut Tree<Parsed>,
-    current_node_id: NodeId,
-) -> Result<bool, ParseError> {
-    let current = tree.get(current_node_id);
-    if current.is_none() {
-        return Err(NoCurrent);
-    }
-    let current = current.unwrap();
-    match current.data().option {
-        VARIABLE(_, is_closed) => Ok(!is_closed),
-        _ => Ok(false),
-    }
+struct Parser {
+    tokens: Peekable<IntoIter<Token>>,
+    ende: usize,
 }
 
-fn close_var(tree: &mut Tree<Parsed>, current_node_id: NodeId) -> Result<(), ParseError> {
-    let current = tree.get_mut(current_node_id);
-    if current.is_none() {
-        return Err(NoCurrent);
+impl Parser {
+    fn formel(&mut self) -> Result<Box<AussagenFunktion>, ParseError> {
+        self.stufe(0)
     }
-    let mut current = current.unwrap();
-    match &current.data().option {
-        VARIABLE(name, is_closed) => {
-            if *is_closed {
-                return Err(VariableAlreadyClosed);
+
+    fn stufe(&mut self, stufe: usize) -> Result<Box<AussagenFunktion>, ParseError> {
+        if stufe == STUFEN.len() {
+            return self.unaer();
+        }
+        let links = self.stufe(stufe + 1)?;
+        let operator = match self.tokens.next_if(|token| STUFEN[stufe].contains(&token.kind)) {
+            Some(token) => token,
+            None => return Ok(links),
+        };
+
+        match operator.kind {
+            TokenKind::Implies => {
+                return Ok(Box::new(AussagenFunktion::IMPLIKATION(links, self.stufe(stufe)?)))
+            }
+            TokenKind::Iff => {
+                return Ok(Box::new(AussagenFunktion::BIIMPLIKATION(links, self.stufe(stufe)?)))
             }
-            current.data().option = VARIABLE(name.clone(), true);
-            Ok(())
+            _ => {}
         }
-        _ => Err(NoVariableToClose),
-    }
-}
 
-fn set_or_append_option(
-    tree: &mut Tree<Parsed>,
-    current_node_id: NodeId,
-    option: ParseOption,
-) -> Result<NodeId, ParseError> {
-    let current = tree.get_mut(current_node_id);
-    if current.is_none() {
-        return Err(NoCurrent);
-    }
-    let mut current = current.unwrap();
-    match current.data().option {
-        UNSPECIFIED() => {
-            set_option(tree, current_node_id, option)?;
-            Ok(current_node_id)
+        let mut operanden = vec![links, self.stufe(stufe + 1)?];
+        while let Some(token) = self.tokens.next_if(|token| STUFEN[stufe].contains(&token.kind)) {
+            if token.kind != operator.kind {
+                return Err(ParseError {
+                    found: Some(token.text),
+                    ..ParseError::new(
+                        MixedOperators {
+                            operator: operator.text,
+                        },
+                        token.span,
+                    )
+                });
+            }
+            if matches!(operator.kind, TokenKind::Nand | TokenKind::Nor) {
+                return Err(ParseError {
+                    found: Some(token.text),
+                    ..ParseError::new(NonAssociative, token.span)
+                });
+            }
+            operanden.push(self.stufe(stufe + 1)?);
         }
-        _ => append_and_move_down(tree, current_node_id, option),
-    }
-}
 
-fn set_option(
-    tree: &mut Tree<Parsed>,
-    current_node_id: NodeId,
-    option: ParseOption,
-) -> Result<(), ParseError> {
-    let current = tree.get_mut(current_node_id);
-    if current.is_none() {
-        return Err(NoCurrent);
+        Ok(Box::new(match operator.kind {
+            TokenKind::And => AussagenFunktion::AND(operanden),
+            TokenKind::Or => AussagenFunktion::OR(operanden),
+            TokenKind::Xor => AussagenFunktion::XOR(operanden),
+            TokenKind::Nand => AussagenFunktion::NAND(operanden),
+            _ => AussagenFunktion::NOR(operanden),
+        }))
     }
-    let mut current = current.unwrap();
-    current.data().option = option;
-    Ok(())
-}
 
-fn has_parent(tree: &mut Tree<Parsed>, current_node_id: NodeId) -> Result<bool, ParseError> {
-    let current = tree.get(current_node_id);
-    if current.is_none() {
-        return Err(NoCurrent);
+    fn unaer(&mut self) -> Result<Box<AussagenFunktion>, ParseError> {
+        let token = match self.tokens.next() {
+            Some(token) => token,
+            None => {
+                return Err(ParseError {
+                    expected: OPERAND.to_vec(),
+                    ..ParseError::new(UnexpectedEnd, self.ende..self.ende)
+                })
+            }
+        };
+
+        match token.kind {
+            TokenKind::Not => Ok(Box::new(AussagenFunktion::NOT(self.unaer()?))),
+            TokenKind::LParen => {
+                let formel = self.formel()?;
+                match self.tokens.next() {
+                    Some(Token {
+                        kind: TokenKind::RParen,
+                        ..
+                    }) => Ok(formel),
+                    Some(naechstes) => Err(ParseError::unexpected(naechstes, OPERATOR)),
+                    None => Err(ParseError::new(
+                        UnclosedParenthesis {
+                            opened_at: token.span.start,
+                        },
+                        token.span,
+                    )),
+                }
+            }
+            TokenKind::True => Ok(Box::new(AussagenFunktion::TOP())),
+            TokenKind::False => Ok(Box::new(AussagenFunktion::BOTTOM())),
+            TokenKind::Identifier(name) => Ok(Box::new(AussagenFunktion::VARIABEL(name))),
+            _ => Err(ParseError::unexpected(token, OPERAND)),
+        }
     }
-    let current = current.unwrap();
-    Ok(current.parent().is_some())
 }
 
-fn add_unspecified_root_and_move_up(
-    tree: &mut Tree<Parsed>,
-    current_node_id: NodeId,
-) -> Result<NodeId, ParseError> {
-    let root_id = tree.root_id();
-    if root_id.is_none() {
-        return Err(NoRoot);
-    }
-    let root_id = root_id.unwrap();
-    if current_node_id != root_id {
-        return Err(CurrentIsNotRoot);
+#[cfg(test)]
+mod test {
+    use crate::aussagen::parsing::parse_function;
+    use crate::aussagen::parsing::ParseErrorKind::{IncompleteOperator, MixedOperators, NonAssociative, UnclosedParenthesis, UnexpectedToken, UnexpectedEnd, UnmatchedParenthesis};
+    use crate::aussagen::structures::AussagenFunktion::{self, *};
+
+    fn var(name: &str) -> Box<AussagenFunktion> {
+        Box::new(VARIABEL(String::from(name)))
     }
 
-    let new_root = tree.set_root(Parsed {
-        option: UNSPECIFIED(),
-    });
-    Ok(new_root)
-}
+    #[test]
+    fn bindungsstaerke_test() {
+        let parsed = parse_function("A & B | -C").expect("Fehler bei parse");
+        assert_eq!(
+            *parsed,
+            OR(vec![Box::new(AND(vec![var("A"), var("B")])), Box::new(NOT(var("C")))])
+        );
 
-mod test {
-    use slab_tree::Tree;
+        let parsed = parse_function("A | B -> C <-> D").expect("Fehler bei parse");
+        assert_eq!(
+            *parsed,
+            BIIMPLIKATION(
+                Box::new(IMPLIKATION(Box::new(OR(vec![var("A"), var("B")])), var("C"))),
+                var("D")
+            )
+        );
 
-    use crate::aussagen::UNSPECIFIED;
-    use crate::aussagen::parsing::{append_unspecified_and_move_down, is_unspecified, move_up, Parsed};
-    use crate::aussagen::parsing::ParseOption::VARIABLE;
+        let parsed = parse_function("A ^ B & C").expect("Fehler bei parse");
+        assert_eq!(*parsed, XOR(vec![var("A"), Box::new(AND(vec![var("B"), var("C")]))]));
+    }
 
     #[test]
-    fn move_up_test() {
-        //Given
-        let mut tree: Tree<Parsed> = Tree::new();
-        let root_id = tree.set_root(Parsed {
-            option: VARIABLE(String::from("ROOT"), false),
-        });
-        let current_node_id = tree
-            .get_mut(root_id)
-            .unwrap()
-            .append(Parsed {
-                option: VARIABLE(String::from("Var"), false),
-            })
-            .node_id();
-
-        //When
-        let ret = move_up(&mut tree, current_node_id).expect("Fehler bei move up");
-
-        //Then
-        assert_eq!(root_id, ret);
+    fn assoziativitaet_test() {
+        let parsed = parse_function("A -> B -> C").expect("Fehler bei parse");
+        assert_eq!(*parsed, IMPLIKATION(var("A"), Box::new(IMPLIKATION(var("B"), var("C")))));
+
+        let parsed = parse_function("A & B & C").expect("Fehler bei parse");
+        assert_eq!(*parsed, AND(vec![var("A"), var("B"), var("C")]));
+
+        let parsed = parse_function("((A & B) & C)").expect("Fehler bei parse");
+        assert_eq!(*parsed, AND(vec![Box::new(AND(vec![var("A"), var("B")])), var("C")]));
     }
 
     #[test]
-    fn append_unspecified_and_move_down_test() {
-        //Given
-        let mut tree: Tree<Parsed> = Tree::new();
-        let root_id = tree.set_root(Parsed {
-            option: VARIABLE(String::from("ROOT"), false),
-        });
-        let current_node_id = tree
-            .get_mut(root_id)
-            .unwrap()
-            .append(Parsed {
-                option: VARIABLE(String::from("Var"), false),
-            })
-            .node_id();
-
-        //When
-        let ret = append_unspecified_and_move_down(&mut tree, current_node_id)
-            .expect("Fehler bei append und move down");
-
-        //Then
-        assert_ne!(root_id, ret);
-        assert_ne!(current_node_id, ret);
-        let ret_node = tree.get(ret).expect("Keine Node");
-        assert_eq!(ret_node.data().option, UNSPECIFIED());
+    fn bezeichner_mit_konstanten_buchstaben_test() {
+        let parsed = parse_function("left & (flag | f) -> true").expect("Fehler bei parse");
         assert_eq!(
-            ret_node.parent().expect("Kein Parent").data().option,
-            VARIABLE(String::from("Var"), false)
+            *parsed,
+            IMPLIKATION(
+                Box::new(AND(vec![var("left"), Box::new(OR(vec![var("flag"), Box::new(BOTTOM())]))])),
+                Box::new(TOP())
+            )
         );
     }
 
     #[test]
-    fn is_unspecified_test() {
-        //Given
-        let mut tree: Tree<Parsed> = Tree::new();
-        let root_id = tree.set_root(Parsed {
-            option: VARIABLE(String::from("ROOT"), false),
-        });
-        let var_id = tree
-            .get_mut(root_id)
-            .unwrap()
-            .append(Parsed {
-                option: VARIABLE(String::from("Var"), false),
-            })
-            .node_id();
-        let current_node_id = tree
-            .get_mut(root_id)
-            .unwrap()
-            .append(Parsed {
-                option: UNSPECIFIED(),
-            })
-            .node_id();
-
-        //When
-        assert!(is_unspecified(&mut tree, current_node_id).expect("Fehler unspecified"));
-        assert!(!is_unspecified(&mut tree, var_id).expect("Fehler unspecified 2"));
+    fn gemischte_operatoren_test() {
+        let fehler = parse_function("A & B !& C").expect_err("Kein Fehler");
+        assert_eq!(
+            fehler.kind,
+            MixedOperators {
+                operator: String::from("&")
+            }
+        );
+        assert_eq!(fehler.span, 6..8);
+
+        let fehler = parse_function("A ↑ B ↑ C").expect_err("Kein Fehler");
+        assert_eq!(fehler.kind, NonAssociative);
+        assert_eq!(fehler.span, 6..7);
+        assert_eq!(fehler.to_string(), "Spalte 7: '↑' ist nicht assoziativ, Ketten müssen geklammert werden");
+        assert!(parse_function("A !| B !| C").is_err());
+        let parsed = parse_function("(A ↑ B) ↑ C").expect("Fehler bei parse");
+        assert_eq!(*parsed, NAND(vec![Box::new(NAND(vec![var("A"), var("B")])), var("C")]));
     }
-}
 
-fn to_structures(slab_tree: &Tree<Parsed>, node_id: NodeId) -> Option<Box<AussagenFunktion>> {
-    match &slab_tree.get(node_id).unwrap().data().option {
-        VARIABLE(name, _) => Some(Box::new(AussagenFunktion::VARIABEL(name.clone()))),
-        ParseOption::TOP() => Some(Box::new(AussagenFunktion::TOP())),
-        ParseOption::BOTTOM() => Some(Box::new(AussagenFunktion::BOTTOM())),
-        ParseOption::NOT() => {
-            let children_id = slab_tree
-                .get(node_id)
-                .unwrap()
-                .children()
-                .next()
-                .unwrap()
-                .node_id();
-            let child = to_structures(slab_tree, children_id);
-            if child.is_none() {
-                None
-            } else {
-                Some(Box::new(AussagenFunktion::NOT(child.unwrap())))
-            }
-        }
-        AND() => {
-            let siblings = slab_tree.get(node_id).unwrap().children();
-            let mut vector = vec![];
-            for ele in siblings {
-                let option = to_structures(slab_tree, ele.node_id());
-                if option.is_some() {
-                    vector.push(option.unwrap());
-                }
-            }
+    #[test]
+    fn unerwartetes_zeichen_test() {
+        let fehler = parse_function("(A & )").expect_err("Kein Fehler");
 
-            Some(Box::new(AussagenFunktion::AND(vector)))
-        }
-        OR() => {
-            let siblings = slab_tree.get(node_id).unwrap().children();
-            let mut vector = vec![];
-            for ele in siblings {
-                let option = to_structures(slab_tree, ele.node_id());
-                if option.is_some() {
-                    vector.push(option.unwrap());
-                }
-            }
+        assert_eq!(fehler.kind, UnexpectedToken);
+        assert_eq!(fehler.span, 5..6);
+        assert_eq!(fehler.found, Some(String::from(")")));
+        assert!(fehler.expected.contains(&"Variable"));
+        assert_eq!(
+            format!("{}", fehler),
+            "Spalte 6: Unerwartetes Symbol ')', erwartet: Variable, ⊤, ⊥, (, ¬"
+        );
 
-            Some(Box::new(AussagenFunktion::OR(vector)))
-        }
-        NOTHING() | UNSPECIFIED() => {
-            if slab_tree.get(node_id).unwrap().children().next().is_none() {
-                return None;
-            }
-            let children_id = slab_tree
-                .get(node_id)
-                .unwrap()
-                .children()
-                .next()
-                .unwrap()
-                .node_id();
-            to_structures(slab_tree, children_id)
-        }
+        let fehler = parse_function("(A B)").expect_err("Kein Fehler");
+        assert_eq!(fehler.span, 3..4);
+        assert!(fehler.expected.contains(&"Operator"));
     }
-}
 
-fn print_tree(slab_tree: &Tree<Parsed>, node_id_option: Option<NodeId>) {
-    match node_id_option {
-        None => {
-            println!();
-            let id = slab_tree.root_id();
-            print_tree(slab_tree, id);
-        }
-        Some(node_id) => {
-            let node_ref = slab_tree.get(node_id).unwrap();
-            println!("{:?} -> ", slab_tree.get(node_id).unwrap().data().option);
-            for node in node_ref.children() {
-                println!("{:?}", node.data().option);
-            }
+    #[test]
+    fn klammer_fehler_test() {
+        let fehler = parse_function("(A & (B | C)").expect_err("Kein Fehler");
+        assert_eq!(fehler.kind, UnclosedParenthesis { opened_at: 0 });
+        assert_eq!(
+            format!("{}", fehler),
+            "Nicht geschlossene Klammer, geöffnet in Spalte 1"
+        );
 
-            println!("-----------");
-            for node in node_ref.children() {
-                print_tree(slab_tree, Some(node.node_id()))
-            }
-        }
+        let fehler = parse_function("(A & B))").expect_err("Kein Fehler");
+        assert_eq!(fehler.kind, UnmatchedParenthesis);
+        assert_eq!(fehler.span, 7..8);
     }
-}
 
-fn move_up(tree: &mut Tree<Parsed>, current_node_id: NodeId) -> Result<NodeId, ParseError> {
-    let current = tree.get(current_node_id);
-    if current.is_none() {
-        return Err(NoCurrent);
-    }
-    let current = current.unwrap();
-    let parent = current.parent();
-    if parent.is_none() {
-        return Err(NoParent);
+    #[test]
+    fn unvollstaendiger_operator_test() {
+        let fehler = parse_function("(A <- B)").expect_err("Kein Fehler");
+        assert_eq!(fehler.kind, IncompleteOperator);
+        assert_eq!(fehler.span, 3..6);
+        assert_eq!(fehler.expected, vec!["<->"]);
+        assert_eq!(fehler.markiere("(A <- B)"), "(A <- B)\n   ^^^");
     }
-    let parent = parent.unwrap();
-    //println!("parent {:?}", parent.data().option);
-
-    match parent.data().option {
-        ParseOption::NOT() => {
-            let parent_id = parent.node_id();
-            match move_up(tree, parent_id.clone()) {
-                Ok(node_id) => Ok(node_id),
-                Err(_) => Ok(parent_id),
-            }
-        }
-        _ => Ok(parent.node_id()),
+
+    #[test]
+    fn unerwartetes_ende_test() {
+        let fehler = parse_function("(A & B) |").expect_err("Kein Fehler");
+        assert_eq!(fehler.kind, UnexpectedEnd);
+        assert_eq!(fehler.span, 9..9);
+
+        let fehler = parse_function("-").expect_err("Kein Fehler");
+        assert_eq!(fehler.kind, UnexpectedEnd);
+        assert_eq!(fehler.span, 1..1);
     }
 }
@@ -0,0 +1,304 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+use crate::aussagen::structures::{AussagenFunktion, FormelKontext};
+
+/// Mehr Variabeln werden nicht geprüft, weil dafür die ganze Wahrheitstabelle berechnet wird.
+pub const MAX_VARIABLEN: usize = 16;
+
+#[derive(Debug, PartialEq)]
+pub enum PostFehler {
+    ZuVieleVariablen(usize),
+}
+
+impl Display for PostFehler {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PostFehler::ZuVieleVariablen(anzahl) => write!(
+                f,
+                "Die Post-Klassen werden nur für bis zu {} Variabeln bestimmt, die Formel hat {}",
+                MAX_VARIABLEN, anzahl
+            ),
+        }
+    }
+}
+
+/// Die fünf maximalen Klone nach Post. Eine Menge von Funktionen ist genau dann funktional vollständig,
+/// wenn sie für jede Klasse eine Funktion enthält, die nicht in ihr liegt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum PostKlasse {
+    NullErhaltend,
+    EinsErhaltend,
+    Selbstdual,
+    Monoton,
+    Affin,
+}
+
+pub const POST_KLASSEN: [PostKlasse; 5] = [
+    PostKlasse::NullErhaltend,
+    PostKlasse::EinsErhaltend,
+    PostKlasse::Selbstdual,
+    PostKlasse::Monoton,
+    PostKlasse::Affin,
+];
+
+impl PostKlasse {
+    /// Das übliche Kürzel der Klasse.
+    pub fn kuerzel(&self) -> &'static str {
+        match self {
+            PostKlasse::NullErhaltend => "T₀",
+            PostKlasse::EinsErhaltend => "T₁",
+            PostKlasse::Selbstdual => "S",
+            PostKlasse::Monoton => "M",
+            PostKlasse::Affin => "L",
+        }
+    }
+}
+
+impl Display for PostKlasse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            PostKlasse::NullErhaltend => "0-erhaltend",
+            PostKlasse::EinsErhaltend => "1-erhaltend",
+            PostKlasse::Selbstdual => "selbstdual",
+            PostKlasse::Monoton => "monoton",
+            PostKlasse::Affin => "affin",
+        };
+        write!(f, "{} ({})", name, self.kuerzel())
+    }
+}
+
+/// In welchen Post-Klassen eine Funktion liegt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct PostKlassen {
+    /// f(0, ..., 0) = 0
+    pub null_erhaltend: bool,
+    /// f(1, ..., 1) = 1
+    pub eins_erhaltend: bool,
+    /// f(¬x₁, ..., ¬xₙ) = ¬f(x₁, ..., xₙ)
+    pub selbstdual: bool,
+    /// Aus x ≤ y folgt f(x) ≤ f(y).
+    pub monoton: bool,
+    /// Die algebraische Normalform hat nur Monome mit höchstens einer Variable.
+    pub affin: bool,
+}
+
+impl PostKlassen {
+    /// Bestimmt die Klassen aus der Wahrheitstabelle über die Variabeln der Funktion.
+    pub fn new(kontext: &FormelKontext, funktion: &AussagenFunktion) -> Result<PostKlassen, PostFehler> {
+        let mut variablen: Vec<&String> = funktion.get_keys(kontext).into_iter().collect();
+        variablen.sort();
+        let n = variablen.len();
+        if n > MAX_VARIABLEN {
+            return Err(PostFehler::ZuVieleVariablen(n));
+        }
+        // Bit j der Zeile ist der Wert der j-ten Variable.
+        let tabelle: Vec<bool> = (0..1usize << n)
+            .map(|zeile| {
+                let belegung: HashMap<String, bool> = variablen
+                    .iter()
+                    .enumerate()
+                    .map(|(j, name)| ((*name).clone(), zeile >> j & 1 == 1))
+                    .collect();
+                funktion.result(kontext, &belegung, false)
+            })
+            .collect();
+        Ok(PostKlassen::aus_tabelle(&tabelle, n))
+    }
+
+    fn aus_tabelle(tabelle: &[bool], n: usize) -> PostKlassen {
+        let alle = (1usize << n) - 1;
+        let selbstdual = (0..tabelle.len()).all(|zeile| tabelle[zeile] != tabelle[alle ^ zeile]);
+        let monoton = (0..tabelle.len()).all(|zeile| {
+            (0..n)
+                .filter(|j| zeile >> j & 1 == 0)
+                .all(|j| !tabelle[zeile] || tabelle[zeile | 1 << j])
+        });
+        // Möbius-Transformation in die algebraische Normalform (Schegalkin-Polynom).
+        let mut anf = tabelle.to_vec();
+        for j in 0..n {
+            for zeile in 0..anf.len() {
+                if zeile >> j & 1 == 1 {
+                    anf[zeile] ^= anf[zeile ^ 1 << j];
+                }
+            }
+        }
+        let affin = anf
+            .iter()
+            .enumerate()
+            .all(|(monom, koeffizient)| !koeffizient || monom.count_ones() <= 1);
+        PostKlassen {
+            null_erhaltend: !tabelle[0],
+            eins_erhaltend: tabelle[alle],
+            selbstdual,
+            monoton,
+            affin,
+        }
+    }
+
+    pub fn liegt_in(&self, klasse: PostKlasse) -> bool {
+        match klasse {
+            PostKlasse::NullErhaltend => self.null_erhaltend,
+            PostKlasse::EinsErhaltend => self.eins_erhaltend,
+            PostKlasse::Selbstdual => self.selbstdual,
+            PostKlasse::Monoton => self.monoton,
+            PostKlasse::Affin => self.affin,
+        }
+    }
+}
+
+impl Display for PostKlassen {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, klasse) in POST_KLASSEN.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}: {}", klasse, if self.liegt_in(*klasse) { "ja" } else { "nein" })?;
+        }
+        Ok(())
+    }
+}
+
+/// Das Ergebnis des Post-Kriteriums für eine Menge von Funktionen.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Vollstaendigkeit {
+    /// Jede Funktion in UTF-Schreibweise mit ihren Klassen.
+    pub funktionen: Vec<(String, PostKlassen)>,
+    /// Die Klassen, in denen alle Funktionen liegen. Ist die Liste leer, ist die Menge funktional vollständig.
+    pub gemeinsame_klassen: Vec<PostKlasse>,
+}
+
+impl Vollstaendigkeit {
+    pub fn new(kontext: &FormelKontext, funktionen: &[&AussagenFunktion]) -> Result<Vollstaendigkeit, PostFehler> {
+        let funktionen = funktionen
+            .iter()
+            .map(|funktion| Ok((funktion.to_utf_string(), PostKlassen::new(kontext, funktion)?)))
+            .collect::<Result<Vec<(String, PostKlassen)>, PostFehler>>()?;
+        let gemeinsame_klassen = POST_KLASSEN
+            .into_iter()
+            .filter(|klasse| funktionen.iter().all(|(_, klassen)| klassen.liegt_in(*klasse)))
+            .collect();
+        Ok(Vollstaendigkeit {
+            funktionen,
+            gemeinsame_klassen,
+        })
+    }
+
+    pub fn ist_vollstaendig(&self) -> bool {
+        self.gemeinsame_klassen.is_empty()
+    }
+}
+
+/// Schreibt eine Tabelle mit ✓ für jede Klasse, in der die Funktion liegt, und darunter das Ergebnis.
+impl Display for Vollstaendigkeit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let breite = self
+            .funktionen
+            .iter()
+            .map(|(name, _)| name.chars().count())
+            .max()
+            .unwrap_or(0);
+        let mut kopf = " ".repeat(breite);
+        for klasse in POST_KLASSEN {
+            kopf.push_str(&format!(" {:<2}", klasse.kuerzel()));
+        }
+        writeln!(f, "{}", kopf.trim_end())?;
+        for (name, klassen) in &self.funktionen {
+            let mut zeile = format!("{}{}", name, " ".repeat(breite - name.chars().count()));
+            for klasse in POST_KLASSEN {
+                zeile.push_str(&format!(" {:<2}", if klassen.liegt_in(klasse) { "✓" } else { "✗" }));
+            }
+            writeln!(f, "{}", zeile.trim_end())?;
+        }
+        if self.ist_vollstaendig() {
+            write!(f, "Funktional vollständig")
+        } else {
+            let klassen: Vec<&str> = self.gemeinsame_klassen.iter().map(PostKlasse::kuerzel).collect();
+            write!(f, "Nicht funktional vollständig, alle Funktionen liegen in {}", klassen.join(", "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::aussagen::parsing::parse_function;
+    use crate::aussagen::post::{PostFehler, PostKlasse, PostKlassen, Vollstaendigkeit};
+    use crate::aussagen::structures::FormelKontext;
+
+    fn klassen(formel: &str) -> PostKlassen {
+        let funktion = parse_function(formel).expect("Fehler bei parse");
+        PostKlassen::new(&FormelKontext::new(), &funktion).expect("Zu viele Variabeln")
+    }
+
+    fn vollstaendigkeit(formeln: &[&str]) -> Vollstaendigkeit {
+        let funktionen: Vec<_> = formeln
+            .iter()
+            .map(|formel| parse_function(formel).expect("Fehler bei parse"))
+            .collect();
+        let funktionen: Vec<_> = funktionen.iter().map(|funktion| &**funktion).collect();
+        Vollstaendigkeit::new(&FormelKontext::new(), &funktionen).expect("Zu viele Variabeln")
+    }
+
+    #[test]
+    fn klassen_test() {
+        let mehrheit = klassen("(A & B) | (A & C) | (B & C)");
+        assert_eq!(
+            mehrheit,
+            PostKlassen {
+                null_erhaltend: true,
+                eins_erhaltend: true,
+                selbstdual: true,
+                monoton: true,
+                affin: false
+            }
+        );
+        let xor = klassen("A ^ B");
+        assert_eq!(
+            xor,
+            PostKlassen {
+                null_erhaltend: true,
+                eins_erhaltend: false,
+                selbstdual: false,
+                monoton: false,
+                affin: true
+            }
+        );
+        let negation = klassen("-A");
+        assert!(negation.selbstdual && negation.affin && !negation.monoton);
+        let konstante = klassen("t");
+        assert!(!konstante.null_erhaltend && konstante.eins_erhaltend && konstante.monoton && !konstante.selbstdual);
+        assert_eq!(
+            format!("{}", klassen("A -> B")),
+            "0-erhaltend (T₀): nein\n1-erhaltend (T₁): ja\nselbstdual (S): nein\nmonoton (M): nein\naffin (L): nein"
+        );
+    }
+
+    #[test]
+    fn vollstaendig_test() {
+        assert!(vollstaendigkeit(&["A !& B"]).ist_vollstaendig());
+        assert!(vollstaendigkeit(&["A -> B", "f"]).ist_vollstaendig());
+        assert!(vollstaendigkeit(&["A & B", "-A"]).ist_vollstaendig());
+        assert_eq!(
+            vollstaendigkeit(&["A & B", "A | B"]).gemeinsame_klassen,
+            vec![PostKlasse::NullErhaltend, PostKlasse::EinsErhaltend, PostKlasse::Monoton]
+        );
+        assert_eq!(vollstaendigkeit(&["A ^ B", "A <-> B"]).gemeinsame_klassen, vec![PostKlasse::Affin]);
+        assert_eq!(
+            format!("{}", vollstaendigkeit(&["A -> B", "-A"])),
+            "        T₀ T₁ S  M  L\n(A → B) ✗  ✓  ✗  ✗  ✗\n¬A      ✗  ✗  ✓  ✗  ✓\nFunktional vollständig"
+        );
+    }
+
+    #[test]
+    fn zu_viele_variablen_test() {
+        let namen: Vec<String> = (0..17).map(|i| format!("x{}", i)).collect();
+        let funktion = parse_function(&namen.join(" & ")).expect("Fehler bei parse");
+        assert_eq!(
+            PostKlassen::new(&FormelKontext::new(), &funktion),
+            Err(PostFehler::ZuVieleVariablen(17))
+        );
+    }
+}
@@ -0,0 +1,241 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet};
+use std::fmt::{Display, Formatter};
+
+use crate::aussagen::klauseln::{Klausel, KlauselMenge, Literal};
+use crate::aussagen::structures::{AussagenFunktion, FormelKontext};
+
+/// Eine Zeile eines Resolutionsbeweises. Klauseln ohne Eltern sind Prämissen aus der Klauselmenge.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ResolutionsSchritt {
+    pub nummer: usize,
+    pub klausel: Klausel,
+    /// Die Nummern der beiden Elternklauseln. Die erste enthält `literal`, die zweite seine Negation.
+    pub eltern: Option<(usize, usize)>,
+    pub literal: Option<Literal>,
+}
+
+/// Eine Herleitung der leeren Klausel. Der letzte Schritt ist immer die leere Klausel.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Resolutionsbeweis {
+    pub schritte: Vec<ResolutionsSchritt>,
+}
+
+impl Display for Resolutionsbeweis {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let texte: Vec<String> = self
+            .schritte
+            .iter()
+            .map(|schritt| {
+                if schritt.klausel.ist_leer() {
+                    String::from("□")
+                } else {
+                    format!("{}", schritt.klausel)
+                }
+            })
+            .collect();
+        let breite = texte.iter().map(|text| text.chars().count()).max().unwrap_or(0);
+        let nummern = self.schritte.len().to_string().len();
+        for (schritt, text) in self.schritte.iter().zip(&texte) {
+            write!(f, "{:>n$}: {:<b$}   ", schritt.nummer, text, n = nummern, b = breite)?;
+            match (&schritt.eltern, &schritt.literal) {
+                (Some((links, rechts)), Some(literal)) => {
+                    writeln!(f, "Resolution aus {} und {} über {}", links, rechts, literal.name)?
+                }
+                _ => writeln!(f, "Prämisse")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Die Literale sortiert, damit gleiche Klauseln gleich aussehen.
+fn normalisiert(literale: Vec<Literal>) -> Klausel {
+    let mut klausel = Klausel::new(literale);
+    klausel.literale.sort();
+    klausel
+}
+
+/// Die Resolvente von `links` und `rechts` über `literal`, das in `links` vorkommt.
+fn resolvente(links: &Klausel, rechts: &Klausel, literal: &Literal) -> Klausel {
+    let negation = literal.negation();
+    normalisiert(
+        links
+            .literale
+            .iter()
+            .filter(|l| *l != literal)
+            .chain(rechts.literale.iter().filter(|l| **l != negation))
+            .cloned()
+            .collect(),
+    )
+}
+
+/// Ob jedes Literal von `klausel` auch in `andere` vorkommt.
+fn subsumiert(klausel: &Klausel, andere: &Klausel) -> bool {
+    klausel.literale.iter().all(|literal| andere.literale.contains(literal))
+}
+
+/// Sucht durch Resolution eine Herleitung der leeren Klausel. Kurze Klauseln werden zuerst verarbeitet,
+/// Tautologien und Klauseln, die von einer bekannten Klausel subsumiert werden, werden verworfen.
+/// Gibt `None` zurück, wenn keine neuen Resolventen mehr entstehen, die Klauselmenge also erfüllbar ist.
+pub fn widerlege(klauselmenge: &KlauselMenge) -> Option<Resolutionsbeweis> {
+    let mut klauseln: Vec<Klausel> = Vec::new();
+    let mut herkunft: Vec<Option<(usize, usize, Literal)>> = Vec::new();
+    let mut bekannt: HashSet<Klausel> = HashSet::new();
+    let mut warteschlange = BinaryHeap::new();
+    let mut verarbeitet: Vec<usize> = Vec::new();
+
+    let mut leer = None;
+    for klausel in &klauselmenge.klauseln {
+        let klausel = normalisiert(klausel.literale.clone());
+        if bekannt.insert(klausel.clone()) {
+            warteschlange.push(Reverse((klausel.literale.len(), klauseln.len())));
+            if klausel.ist_leer() {
+                leer = Some(klauseln.len());
+            }
+            klauseln.push(klausel);
+            herkunft.push(None);
+        }
+    }
+
+    while leer.is_none() {
+        let index = match warteschlange.pop() {
+            Some(Reverse((_, index))) => index,
+            None => return None,
+        };
+        if verarbeitet.iter().any(|andere| subsumiert(&klauseln[*andere], &klauseln[index])) {
+            continue;
+        }
+        let gegeben = klauseln[index].clone();
+        'partner: for partner in verarbeitet.iter().copied().chain([index]) {
+            for literal in &gegeben.literale {
+                if !klauseln[partner].literale.contains(&literal.negation()) {
+                    continue;
+                }
+                let neu = resolvente(&gegeben, &klauseln[partner], literal);
+                if neu.ist_tautologie()
+                    || bekannt.contains(&neu)
+                    || klauseln.iter().any(|klausel| subsumiert(klausel, &neu))
+                {
+                    continue;
+                }
+                bekannt.insert(neu.clone());
+                warteschlange.push(Reverse((neu.literale.len(), klauseln.len())));
+                herkunft.push(Some((index, partner, literal.clone())));
+                if neu.ist_leer() {
+                    leer = Some(klauseln.len());
+                    klauseln.push(neu);
+                    break 'partner;
+                }
+                klauseln.push(neu);
+            }
+        }
+        verarbeitet.push(index);
+    }
+
+    Some(beweis(&klauseln, &herkunft, leer.unwrap()))
+}
+
+/// Behält nur die Klauseln, die für die leere Klausel gebraucht werden, und nummeriert sie neu ab 1.
+fn beweis(
+    klauseln: &[Klausel],
+    herkunft: &[Option<(usize, usize, Literal)>],
+    leer: usize,
+) -> Resolutionsbeweis {
+    let mut gebraucht = vec![false; klauseln.len()];
+    let mut offen = vec![leer];
+    while let Some(index) = offen.pop() {
+        if gebraucht[index] {
+            continue;
+        }
+        gebraucht[index] = true;
+        if let Some((links, rechts, _)) = &herkunft[index] {
+            offen.push(*links);
+            offen.push(*rechts);
+        }
+    }
+
+    // Prämissen zuerst, danach die Resolventen in der Reihenfolge ihrer Herleitung.
+    let reihenfolge: Vec<usize> = (0..klauseln.len())
+        .filter(|index| gebraucht[*index] && herkunft[*index].is_none())
+        .chain((0..klauseln.len()).filter(|index| gebraucht[*index] && herkunft[*index].is_some()))
+        .collect();
+    let mut nummern = vec![0; klauseln.len()];
+    for (nummer, index) in reihenfolge.iter().enumerate() {
+        nummern[*index] = nummer + 1;
+    }
+    let schritte = reihenfolge
+        .iter()
+        .map(|index| ResolutionsSchritt {
+            nummer: nummern[*index],
+            klausel: klauseln[*index].clone(),
+            eltern: herkunft[*index]
+                .as_ref()
+                .map(|(links, rechts, _)| (nummern[*links], nummern[*rechts])),
+            literal: herkunft[*index].as_ref().map(|(_, _, literal)| literal.clone()),
+        })
+        .collect();
+    Resolutionsbeweis { schritte }
+}
+
+impl AussagenFunktion {
+    /// Widerlegt die Funktion durch Resolution über ihrer Klauselmenge aus [`Self::to_knf`].
+    pub fn widerlege(&self, kontext: &FormelKontext) -> Option<Resolutionsbeweis> {
+        widerlege(&self.to_knf(kontext))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::aussagen::klauseln::{Klausel, KlauselMenge, Literal};
+    use crate::aussagen::parsing::parse_function;
+    use crate::aussagen::resolution::widerlege;
+    use crate::aussagen::structures::FormelKontext;
+
+    #[test]
+    fn widerlege_test() {
+        let kontext = FormelKontext::new();
+        let funktion = parse_function("(A | B) & (-A | B) & (A | -B) & (-A | -B)").expect("Fehler bei parse");
+        let beweis = funktion.widerlege(&kontext).expect("Keine Widerlegung");
+        let letzter = beweis.schritte.last().unwrap();
+        assert!(letzter.klausel.ist_leer());
+        for schritt in &beweis.schritte {
+            if let (Some((links, rechts)), Some(literal)) = (schritt.eltern, &schritt.literal) {
+                assert!(links < schritt.nummer && rechts < schritt.nummer);
+                assert!(beweis.schritte[links - 1].klausel.literale.contains(literal));
+                assert!(beweis.schritte[rechts - 1].klausel.literale.contains(&literal.negation()));
+            }
+        }
+        assert_eq!(beweis.schritte.iter().filter(|schritt| schritt.eltern.is_none()).count(), 4);
+
+        let funktion = parse_function("(A -> B) & A & -B & (C | D)").expect("Fehler bei parse");
+        let beweis = funktion.widerlege(&kontext).expect("Keine Widerlegung");
+        assert_eq!(beweis.schritte.len(), 5);
+        assert_eq!(
+            format!("{}", beweis),
+            concat!(
+                "1: {¬A, B}   Prämisse\n",
+                "2: {A}       Prämisse\n",
+                "3: {¬B}      Prämisse\n",
+                "4: {B}       Resolution aus 1 und 2 über A\n",
+                "5: □         Resolution aus 4 und 3 über B\n",
+            )
+        );
+    }
+
+    #[test]
+    fn erfuellbar_test() {
+        let kontext = FormelKontext::new();
+        let funktion = parse_function("(A | B) & (-A | C) & (-B | -C)").expect("Fehler bei parse");
+        assert!(funktion.widerlege(&kontext).is_none());
+        assert!(widerlege(&KlauselMenge::default()).is_none());
+
+        let mut leer = KlauselMenge::default();
+        leer.add(Klausel::new(vec![Literal::positiv("A")]));
+        leer.add(Klausel::default());
+        let beweis = widerlege(&leer).expect("Keine Widerlegung");
+        assert_eq!(beweis.schritte.len(), 1);
+    }
+}
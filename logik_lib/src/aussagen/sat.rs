@@ -0,0 +1,439 @@
+use std::collections::HashMap;
+
+use crate::aussagen::klauseln::{Klausel, KlauselMenge};
+
+/// Anzahl der Konflikte, mit der die Luby-Folge für die Neustarts multipliziert wird.
+const NEUSTART_BASIS: u64 = 100;
+/// Faktor, mit dem die Aktivität älterer Variabeln nach jedem Konflikt abnimmt.
+const AKTIVITAET_ABNAHME: f64 = 0.95;
+
+/// Ein CDCL-SAT-Solver für Klauselmengen mit Unit-Propagation über Watched Literals, Klausellernen nach dem
+/// ersten Unique Implication Point, VSIDS-Variabelwahl mit Phase-Saving und Neustarts nach der Luby-Folge.
+///
+/// Nach [`SatSolver::loese`] können weitere Klauseln hinzugefügt und erneut gelöst werden, gelernte Klauseln bleiben erhalten.
+///
+/// Intern ist ein Literal `2 * variable + negiert`.
+#[derive(Debug, Default)]
+pub struct SatSolver {
+    namen: Vec<String>,
+    indizes: HashMap<String, usize>,
+    klauseln: Vec<Vec<usize>>,
+    /// Je Literal die Klauseln, in denen es eines der ersten beiden Literale ist.
+    beobachter: Vec<Vec<usize>>,
+    werte: Vec<Option<bool>>,
+    stufen: Vec<usize>,
+    gruende: Vec<Option<usize>>,
+    spur: Vec<usize>,
+    spur_grenzen: Vec<usize>,
+    propagiert: usize,
+    aktivitaet: Vec<f64>,
+    zuwachs: f64,
+    phasen: Vec<bool>,
+    widerspruechlich: bool,
+    konflikte: u64,
+}
+
+fn variable(literal: usize) -> usize {
+    literal >> 1
+}
+
+fn wert(werte: &[Option<bool>], literal: usize) -> Option<bool> {
+    werte[variable(literal)].map(|wert| wert == (literal & 1 == 0))
+}
+
+/// Das i-te Glied (ab 1) der Luby-Folge 1, 1, 2, 1, 1, 2, 4, 1, ...
+fn luby(mut i: u64) -> u64 {
+    loop {
+        let mut k = 1;
+        while (1 << k) - 1 < i {
+            k += 1;
+        }
+        if i == (1 << k) - 1 {
+            return 1 << (k - 1);
+        }
+        i -= (1 << (k - 1)) - 1;
+    }
+}
+
+impl SatSolver {
+    pub fn new() -> SatSolver {
+        SatSolver {
+            zuwachs: 1.0,
+            ..SatSolver::default()
+        }
+    }
+
+    /// Die Anzahl der Konflikte aller bisherigen Aufrufe von [`SatSolver::loese`].
+    pub fn konflikte(&self) -> u64 {
+        self.konflikte
+    }
+
+    fn variable_fuer(&mut self, name: &str) -> usize {
+        if let Some(index) = self.indizes.get(name) {
+            return *index;
+        }
+        let index = self.namen.len();
+        self.namen.push(String::from(name));
+        self.indizes.insert(String::from(name), index);
+        self.beobachter.push(Vec::new());
+        self.beobachter.push(Vec::new());
+        self.werte.push(None);
+        self.stufen.push(0);
+        self.gruende.push(None);
+        self.aktivitaet.push(0.0);
+        self.phasen.push(false);
+        index
+    }
+
+    /// Macht die Variable bekannt, auch wenn sie in keiner Klausel vorkommt. Sie erscheint dann im Modell.
+    pub fn add_variable(&mut self, name: &str) {
+        self.variable_fuer(name);
+    }
+
+    pub fn add_klauseln(&mut self, klauseln: &KlauselMenge) {
+        for klausel in &klauseln.klauseln {
+            self.add_klausel(klausel);
+        }
+    }
+
+    pub fn add_klausel(&mut self, klausel: &Klausel) {
+        self.zurueck(0);
+        let mut literale: Vec<usize> = klausel
+            .literale
+            .iter()
+            .map(|literal| 2 * self.variable_fuer(&literal.name) + literal.negiert as usize)
+            .collect();
+        literale.sort_unstable();
+        literale.dedup();
+        if literale.windows(2).any(|paar| paar[0] ^ 1 == paar[1]) {
+            return;
+        }
+        // Auf Stufe 0 belegte Literale sind endgültig.
+        if literale.iter().any(|literal| wert(&self.werte, *literal) == Some(true)) {
+            return;
+        }
+        literale.retain(|literal| wert(&self.werte, *literal).is_none());
+        match literale.len() {
+            0 => self.widerspruechlich = true,
+            1 => self.setze(literale[0], None),
+            _ => {
+                self.beobachte(literale);
+            }
+        }
+    }
+
+    fn beobachte(&mut self, literale: Vec<usize>) -> usize {
+        let index = self.klauseln.len();
+        self.beobachter[literale[0]].push(index);
+        self.beobachter[literale[1]].push(index);
+        self.klauseln.push(literale);
+        index
+    }
+
+    fn setze(&mut self, literal: usize, grund: Option<usize>) {
+        let variable = variable(literal);
+        self.werte[variable] = Some(literal & 1 == 0);
+        self.stufen[variable] = self.spur_grenzen.len();
+        self.gruende[variable] = grund;
+        self.spur.push(literal);
+    }
+
+    /// Nimmt alle Entscheidungen oberhalb von `stufe` zurück.
+    fn zurueck(&mut self, stufe: usize) {
+        if self.spur_grenzen.len() <= stufe {
+            return;
+        }
+        let grenze = self.spur_grenzen[stufe];
+        for literal in self.spur.drain(grenze..) {
+            let variable = variable(literal);
+            self.phasen[variable] = literal & 1 == 0;
+            self.werte[variable] = None;
+            self.gruende[variable] = None;
+        }
+        self.spur_grenzen.truncate(stufe);
+        self.propagiert = self.propagiert.min(self.spur.len());
+    }
+
+    /// Unit-Propagation. Gibt die Klausel zurück, die dabei falsch geworden ist.
+    fn propagiere(&mut self) -> Option<usize> {
+        while self.propagiert < self.spur.len() {
+            let falsch = self.spur[self.propagiert] ^ 1;
+            self.propagiert += 1;
+            let mut beobachtet = std::mem::take(&mut self.beobachter[falsch]);
+            let mut konflikt = None;
+            let mut i = 0;
+            while i < beobachtet.len() {
+                let index = beobachtet[i];
+                let klausel = &mut self.klauseln[index];
+                if klausel[0] == falsch {
+                    klausel.swap(0, 1);
+                }
+                let erstes = klausel[0];
+                if wert(&self.werte, erstes) == Some(true) {
+                    i += 1;
+                    continue;
+                }
+                let ersatz = (2..klausel.len()).find(|j| wert(&self.werte, klausel[*j]) != Some(false));
+                if let Some(j) = ersatz {
+                    klausel.swap(1, j);
+                    self.beobachter[klausel[1]].push(index);
+                    beobachtet.swap_remove(i);
+                    continue;
+                }
+                if wert(&self.werte, erstes) == Some(false) {
+                    konflikt = Some(index);
+                    break;
+                }
+                self.setze(erstes, Some(index));
+                i += 1;
+            }
+            self.beobachter[falsch] = beobachtet;
+            if konflikt.is_some() {
+                return konflikt;
+            }
+        }
+        None
+    }
+
+    fn erhoehe_aktivitaet(&mut self, variable: usize) {
+        self.aktivitaet[variable] += self.zuwachs;
+        if self.aktivitaet[variable] > 1e100 {
+            for aktivitaet in &mut self.aktivitaet {
+                *aktivitaet *= 1e-100;
+            }
+            self.zuwachs *= 1e-100;
+        }
+    }
+
+    /// Leitet aus dem Konflikt eine Klausel nach dem ersten Unique Implication Point ab.
+    /// Das erste Literal der Klausel wird nach dem Zurückspringen auf die zurückgegebene Stufe wahr.
+    fn analysiere(&mut self, konflikt: usize) -> (Vec<usize>, usize) {
+        let aktuelle_stufe = self.spur_grenzen.len();
+        let mut gesehen = vec![false; self.namen.len()];
+        let mut gelernt = vec![0];
+        let mut offen = 0;
+        let mut klausel = konflikt;
+        let mut implizit = None;
+        let mut index = self.spur.len();
+        loop {
+            for i in 0..self.klauseln[klausel].len() {
+                let literal = self.klauseln[klausel][i];
+                let variable = variable(literal);
+                if Some(literal) == implizit || gesehen[variable] || self.stufen[variable] == 0 {
+                    continue;
+                }
+                gesehen[variable] = true;
+                self.erhoehe_aktivitaet(variable);
+                if self.stufen[variable] == aktuelle_stufe {
+                    offen += 1;
+                } else {
+                    gelernt.push(literal);
+                }
+            }
+            loop {
+                index -= 1;
+                if gesehen[variable(self.spur[index])] {
+                    break;
+                }
+            }
+            let literal = self.spur[index];
+            gesehen[variable(literal)] = false;
+            offen -= 1;
+            if offen == 0 {
+                gelernt[0] = literal ^ 1;
+                break;
+            }
+            klausel = self.gruende[variable(literal)].expect("Implizierte Variable ohne Grund");
+            implizit = Some(literal);
+        }
+
+        if gelernt.len() == 1 {
+            return (gelernt, 0);
+        }
+        let hoechste = (1..gelernt.len())
+            .max_by_key(|i| self.stufen[variable(gelernt[*i])])
+            .unwrap();
+        gelernt.swap(1, hoechste);
+        let stufe = self.stufen[variable(gelernt[1])];
+        (gelernt, stufe)
+    }
+
+    /// Die unbelegte Variable mit der höchsten Aktivität, mit ihrer zuletzt gesetzten Phase.
+    fn waehle(&self) -> Option<usize> {
+        (0..self.namen.len())
+            .filter(|variable| self.werte[*variable].is_none())
+            .max_by(|a, b| self.aktivitaet[*a].total_cmp(&self.aktivitaet[*b]))
+            .map(|variable| 2 * variable + !self.phasen[variable] as usize)
+    }
+
+    /// Sucht eine erfüllende Belegung aller bekannten Variabeln.
+    pub fn loese(&mut self) -> Option<HashMap<String, bool>> {
+        if self.widerspruechlich {
+            return None;
+        }
+        self.zurueck(0);
+        let mut neustarts = 1;
+        let mut konflikte_seit_neustart = 0;
+        loop {
+            if let Some(konflikt) = self.propagiere() {
+                self.konflikte += 1;
+                if self.spur_grenzen.is_empty() {
+                    self.widerspruechlich = true;
+                    return None;
+                }
+                let (gelernt, stufe) = self.analysiere(konflikt);
+                self.zurueck(stufe);
+                let behauptet = gelernt[0];
+                let grund = if gelernt.len() > 1 {
+                    Some(self.beobachte(gelernt))
+                } else {
+                    None
+                };
+                self.setze(behauptet, grund);
+                self.zuwachs /= AKTIVITAET_ABNAHME;
+                konflikte_seit_neustart += 1;
+                continue;
+            }
+            if konflikte_seit_neustart >= NEUSTART_BASIS * luby(neustarts) {
+                self.zurueck(0);
+                neustarts += 1;
+                konflikte_seit_neustart = 0;
+                continue;
+            }
+            match self.waehle() {
+                Some(literal) => {
+                    self.spur_grenzen.push(self.spur.len());
+                    self.setze(literal, None);
+                }
+                None => {
+                    let modell = self
+                        .namen
+                        .iter()
+                        .cloned()
+                        .zip(self.werte.iter().map(|wert| wert.unwrap()))
+                        .collect();
+                    self.zurueck(0);
+                    return Some(modell);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use crate::aussagen::bdd::BddManager;
+    use crate::aussagen::ist_erfuellbar;
+    use crate::aussagen::klauseln::{Klausel, KlauselMenge, Literal};
+    use crate::aussagen::parsing::parse_function;
+    use crate::aussagen::sat::{luby, SatSolver};
+    use crate::aussagen::structures::FormelKontext;
+
+    fn literal(name: String, negiert: bool) -> Literal {
+        Literal { name, negiert }
+    }
+
+    fn erfuellt(klauseln: &KlauselMenge, modell: &HashMap<String, bool>) -> bool {
+        klauseln.klauseln.iter().all(|klausel| {
+            klausel
+                .literale
+                .iter()
+                .any(|literal| modell[&literal.name] != literal.negiert)
+        })
+    }
+
+    #[test]
+    fn luby_test() {
+        let folge: Vec<u64> = (1..=15).map(luby).collect();
+        assert_eq!(folge, vec![1, 1, 2, 1, 1, 2, 4, 1, 1, 2, 1, 1, 2, 4, 8]);
+    }
+
+    #[test]
+    fn schubfach_test() {
+        // 5 Tauben passen nicht in 4 Schubfächer.
+        let mut solver = SatSolver::new();
+        let p = |taube: usize, fach: usize, negiert: bool| literal(format!("p{}_{}", taube, fach), negiert);
+        for taube in 0..5 {
+            solver.add_klausel(&Klausel::new((0..4).map(|fach| p(taube, fach, false)).collect()));
+        }
+        for fach in 0..4 {
+            for a in 0..5 {
+                for b in a + 1..5 {
+                    solver.add_klausel(&Klausel::new(vec![p(a, fach, true), p(b, fach, true)]));
+                }
+            }
+        }
+        assert!(solver.loese().is_none());
+        assert!(solver.konflikte() > 0);
+    }
+
+    #[test]
+    fn zufall_test() {
+        // Zufällige 3-KNF nahe der Phasengrenze, verglichen mit dem BDD.
+        let mut zustand: u64 = 12345;
+        let mut zufall = |grenze: u64| {
+            zustand = zustand.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (zustand >> 33) % grenze
+        };
+        for _ in 0..30 {
+            let mut klauseln = KlauselMenge::default();
+            for _ in 0..52 {
+                let literale = (0..3)
+                    .map(|_| literal(format!("x{}", zufall(12)), zufall(2) == 1))
+                    .collect();
+                klauseln.add(Klausel::new(literale));
+            }
+            let mut solver = SatSolver::new();
+            solver.add_klauseln(&klauseln);
+            let modell = solver.loese();
+
+            let kontext = FormelKontext::new();
+            let mut manager = BddManager::new();
+            let bdd = manager.aus_funktion(&kontext, &klauseln.to_funktion());
+            assert_eq!(modell.is_some(), manager.ist_erfuellbar(bdd), "{}", klauseln);
+            if let Some(modell) = modell {
+                assert!(erfuellt(&klauseln, &modell), "{}", klauseln);
+            }
+        }
+    }
+
+    #[test]
+    fn inkrementell_test() {
+        let mut solver = SatSolver::new();
+        solver.add_klausel(&Klausel::new(vec![Literal::positiv("A"), Literal::positiv("B")]));
+        let erstes = solver.loese().expect("Nicht erfüllbar");
+        let blockiert = erstes.iter().map(|(name, wert)| literal(name.clone(), *wert)).collect();
+        solver.add_klausel(&Klausel::new(blockiert));
+        let zweites = solver.loese().expect("Nicht erfüllbar");
+        assert_ne!(erstes, zweites);
+
+        solver.add_klausel(&Klausel::new(vec![Literal::negativ("A")]));
+        solver.add_klausel(&Klausel::new(vec![Literal::negativ("B")]));
+        assert!(solver.loese().is_none());
+    }
+
+    #[test]
+    fn ist_erfuellbar_test() {
+        let mut kontext = FormelKontext::new();
+        kontext
+            .funktionen
+            .insert(String::from("phi"), *parse_function("A -> B").expect("Fehler bei parse"));
+        let funktion = parse_function("phi & A & (C ^ D)").expect("Fehler bei parse");
+        let modell = ist_erfuellbar(&kontext, &funktion).expect("Nicht erfüllbar");
+        assert_eq!(modell.len(), 4);
+        assert!(funktion.result(&kontext, &modell, false));
+
+        let funktion = parse_function("phi & A & -B").expect("Fehler bei parse");
+        assert!(ist_erfuellbar(&kontext, &funktion).is_none());
+
+        let namen: Vec<String> = (0..60).map(|i| format!("x{}", i)).collect();
+        let kette: Vec<String> = namen.windows(2).map(|paar| format!("({} -> {})", paar[0], paar[1])).collect();
+        let funktion = parse_function(&format!("{} & x0 & -x59", kette.join(" & "))).expect("Fehler bei parse");
+        assert!(ist_erfuellbar(&kontext, &funktion).is_none());
+        let funktion = parse_function(&format!("{} & x0", kette.join(" & "))).expect("Fehler bei parse");
+        let modell = ist_erfuellbar(&kontext, &funktion).expect("Nicht erfüllbar");
+        assert!(modell.values().all(|wert| *wert));
+    }
+}
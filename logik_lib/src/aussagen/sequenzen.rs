@@ -0,0 +1,398 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+use crate::aussagen::structures::{werte_to_string, AussagenFunktion, FormelKontext};
+
+/// Eine Sequenz `Γ ⊢ Δ` in der Herleitung zusammen mit der Regel, die sie aus ihren Prämissen herleitet.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SequenzKnoten {
+    pub links: Vec<String>,
+    pub rechts: Vec<String>,
+    /// `None` für ein Blatt, auf das keine Regel mehr anwendbar ist und das kein Axiom ist.
+    pub regel: Option<String>,
+    pub praemissen: Vec<SequenzKnoten>,
+    /// Für ein fehlschlagendes Blatt: die Atome links wahr, die rechts falsch.
+    pub gegenmodell: Option<HashMap<String, bool>>,
+}
+
+/// Eine Herleitung im Sequenzenkalkül LK, rückwärts von der gesuchten Sequenz aus gebaut.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Sequenzbeweis {
+    pub wurzel: SequenzKnoten,
+    /// Alle Blätter sind Axiome, die Sequenz ist also gültig.
+    pub beweisbar: bool,
+}
+
+impl Sequenzbeweis {
+    /// Sucht eine Herleitung für `links ⊢ rechts`. Alle Regeln sind umkehrbar, daher entscheidet die Suche
+    /// ohne Backtracking. Regeln mit einer Prämisse werden vor verzweigenden Regeln angewendet,
+    /// Verweise auf Funktionen aus dem Kontext löst die Regel `Def` auf.
+    pub fn new(kontext: &FormelKontext, links: &[&AussagenFunktion], rechts: &[&AussagenFunktion]) -> Sequenzbeweis {
+        let mut links_neu = Vec::new();
+        fuege_hinzu(&mut links_neu, links.iter().map(|funktion| (*funktion).clone()));
+        let mut rechts_neu = Vec::new();
+        fuege_hinzu(&mut rechts_neu, rechts.iter().map(|funktion| (*funktion).clone()));
+        let wurzel = suche(kontext, links_neu, rechts_neu);
+        Sequenzbeweis {
+            beweisbar: ist_geschlossen(&wurzel),
+            wurzel,
+        }
+    }
+
+    /// Das Gegenmodell des ersten fehlschlagenden Blatts.
+    pub fn gegenmodell(&self) -> Option<&HashMap<String, bool>> {
+        finde_gegenmodell(&self.wurzel)
+    }
+
+    /// Die Herleitung als `prooftree` für das LaTeX-Paket `bussproofs`.
+    pub fn to_latex(&self) -> String {
+        let mut latex = String::from("\\begin{prooftree}\n");
+        schreibe_latex(&self.wurzel, &mut latex);
+        latex.push_str("\\end{prooftree}");
+        latex
+    }
+}
+
+fn ist_geschlossen(knoten: &SequenzKnoten) -> bool {
+    knoten.regel.is_some() && knoten.praemissen.iter().all(ist_geschlossen)
+}
+
+fn finde_gegenmodell(knoten: &SequenzKnoten) -> Option<&HashMap<String, bool>> {
+    knoten
+        .gegenmodell
+        .as_ref()
+        .or_else(|| knoten.praemissen.iter().find_map(finde_gegenmodell))
+}
+
+/// Fügt die Formeln hinzu, die noch nicht auf der Seite stehen.
+fn fuege_hinzu(seite: &mut Vec<AussagenFunktion>, funktionen: impl IntoIterator<Item = AussagenFunktion>) {
+    for funktion in funktionen {
+        if !seite.contains(&funktion) {
+            seite.push(funktion);
+        }
+    }
+}
+
+/// Die Teilformeln ab der zweiten, wieder mit demselben Junktor verbunden. So verzweigen Regeln höchstens zweifach.
+fn rest(
+    funktionen: &[Box<AussagenFunktion>],
+    junktor: fn(Vec<Box<AussagenFunktion>>) -> AussagenFunktion,
+) -> AussagenFunktion {
+    if funktionen.len() == 1 {
+        (*funktionen[0]).clone()
+    } else {
+        junktor(funktionen.to_vec())
+    }
+}
+
+/// Die Prämissen einer Regel: für jede die Formeln, die links und rechts dazukommen.
+type Praemissen = Vec<(Vec<AussagenFunktion>, Vec<AussagenFunktion>)>;
+
+/// Die Regel für die Formel auf der linken (`links == true`) oder rechten Seite. `None` für Atome, ⊤ und ⊥.
+fn regel(kontext: &FormelKontext, funktion: &AussagenFunktion, links: bool) -> Option<(&'static str, Praemissen)> {
+    let alle = |funktionen: &[Box<AussagenFunktion>]| -> Vec<AussagenFunktion> {
+        funktionen.iter().map(|teil| (**teil).clone()).collect()
+    };
+    let seite = |funktionen: Vec<AussagenFunktion>| {
+        if links {
+            (funktionen, vec![])
+        } else {
+            (vec![], funktionen)
+        }
+    };
+    let andere = |funktionen: Vec<AussagenFunktion>| {
+        if links {
+            (vec![], funktionen)
+        } else {
+            (funktionen, vec![])
+        }
+    };
+    let (name, praemissen) = match (funktion, links) {
+        (AussagenFunktion::VARIABEL(name), _) => ("Def", vec![seite(vec![kontext.funktionen.get(name)?.clone()])]),
+        (AussagenFunktion::TOP() | AussagenFunktion::BOTTOM(), _) => return None,
+        (AussagenFunktion::NOT(inner), true) => ("¬L", vec![andere(vec![(**inner).clone()])]),
+        (AussagenFunktion::NOT(inner), false) => ("¬R", vec![andere(vec![(**inner).clone()])]),
+        (AussagenFunktion::AND(teile), true) => ("⋀L", vec![seite(alle(teile))]),
+        (AussagenFunktion::AND(teile), false) => (
+            "⋀R",
+            vec![seite(vec![(*teile[0]).clone()]), seite(vec![rest(&teile[1..], AussagenFunktion::AND)])],
+        ),
+        (AussagenFunktion::OR(teile), true) => (
+            "⋁L",
+            vec![seite(vec![(*teile[0]).clone()]), seite(vec![rest(&teile[1..], AussagenFunktion::OR)])],
+        ),
+        (AussagenFunktion::OR(teile), false) => ("⋁R", vec![seite(alle(teile))]),
+        (AussagenFunktion::NAND(teile), true) => (
+            "↑L",
+            vec![andere(vec![(*teile[0]).clone()]), andere(vec![rest(&teile[1..], AussagenFunktion::AND)])],
+        ),
+        (AussagenFunktion::NAND(teile), false) => ("↑R", vec![andere(alle(teile))]),
+        (AussagenFunktion::NOR(teile), true) => ("↓L", vec![andere(alle(teile))]),
+        (AussagenFunktion::NOR(teile), false) => (
+            "↓R",
+            vec![andere(vec![(*teile[0]).clone()]), andere(vec![rest(&teile[1..], AussagenFunktion::OR)])],
+        ),
+        (AussagenFunktion::IMPLIKATION(a, b), true) => (
+            "→L",
+            vec![(vec![], vec![(**a).clone()]), (vec![(**b).clone()], vec![])],
+        ),
+        (AussagenFunktion::IMPLIKATION(a, b), false) => ("→R", vec![(vec![(**a).clone()], vec![(**b).clone()])]),
+        (AussagenFunktion::BIIMPLIKATION(a, b), true) => (
+            "↔L",
+            vec![
+                (vec![(**a).clone(), (**b).clone()], vec![]),
+                (vec![], vec![(**a).clone(), (**b).clone()]),
+            ],
+        ),
+        (AussagenFunktion::BIIMPLIKATION(a, b), false) => (
+            "↔R",
+            vec![
+                (vec![(**a).clone()], vec![(**b).clone()]),
+                (vec![(**b).clone()], vec![(**a).clone()]),
+            ],
+        ),
+        (AussagenFunktion::XOR(teile), _) => {
+            let name = if links { "⊕L" } else { "⊕R" };
+            match teile.as_slice() {
+                [] => return None,
+                [einzige] => (name, vec![seite(vec![(**einzige).clone()])]),
+                [erste, weitere @ ..] => {
+                    let erste = (**erste).clone();
+                    let weitere = rest(weitere, AussagenFunktion::XOR);
+                    if links {
+                        (name, vec![(vec![erste.clone()], vec![weitere.clone()]), (vec![weitere], vec![erste])])
+                    } else {
+                        (name, vec![(vec![erste.clone(), weitere.clone()], vec![]), (vec![], vec![erste, weitere])])
+                    }
+                }
+            }
+        }
+    };
+    Some((name, praemissen))
+}
+
+/// Baut die Herleitung für `links ⊢ rechts` rückwärts auf.
+fn suche(kontext: &FormelKontext, links: Vec<AussagenFunktion>, rechts: Vec<AussagenFunktion>) -> SequenzKnoten {
+    let mut knoten = SequenzKnoten {
+        links: links.iter().map(|funktion| funktion.to_utf_string()).collect(),
+        rechts: rechts.iter().map(|funktion| funktion.to_utf_string()).collect(),
+        regel: None,
+        praemissen: Vec::new(),
+        gegenmodell: None,
+    };
+    if links.contains(&AussagenFunktion::BOTTOM()) {
+        knoten.regel = Some(String::from("⊥L"));
+        return knoten;
+    }
+    if rechts.contains(&AussagenFunktion::TOP()) {
+        knoten.regel = Some(String::from("⊤R"));
+        return knoten;
+    }
+    if links.iter().any(|funktion| rechts.contains(funktion)) {
+        knoten.regel = Some(String::from("Ax"));
+        return knoten;
+    }
+
+    let mut regeln: Vec<(bool, usize, &'static str, Praemissen)> = links
+        .iter()
+        .enumerate()
+        .map(|(i, funktion)| (true, i, funktion))
+        .chain(rechts.iter().enumerate().map(|(i, funktion)| (false, i, funktion)))
+        .filter_map(|(seite, i, funktion)| {
+            regel(kontext, funktion, seite).map(|(name, praemissen)| (seite, i, name, praemissen))
+        })
+        .collect();
+    regeln.sort_by_key(|(_, _, _, praemissen)| praemissen.len() > 1);
+    match regeln.into_iter().next() {
+        Some((seite, i, name, praemissen)) => {
+            knoten.regel = Some(String::from(name));
+            knoten.praemissen = praemissen
+                .into_iter()
+                .map(|(links_dazu, rechts_dazu)| {
+                    let mut links_neu = links.clone();
+                    let mut rechts_neu = rechts.clone();
+                    if seite {
+                        links_neu.remove(i);
+                    } else {
+                        rechts_neu.remove(i);
+                    }
+                    fuege_hinzu(&mut links_neu, links_dazu);
+                    fuege_hinzu(&mut rechts_neu, rechts_dazu);
+                    suche(kontext, links_neu, rechts_neu)
+                })
+                .collect();
+        }
+        None => {
+            let atome = |funktionen: &[AussagenFunktion], wert: bool| -> Vec<(String, bool)> {
+                funktionen
+                    .iter()
+                    .filter_map(|funktion| match funktion {
+                        AussagenFunktion::VARIABEL(name) => Some((name.clone(), wert)),
+                        _ => None,
+                    })
+                    .collect()
+            };
+            let mut gegenmodell: HashMap<String, bool> = atome(&links, true).into_iter().collect();
+            gegenmodell.extend(atome(&rechts, false));
+            knoten.gegenmodell = Some(gegenmodell);
+        }
+    }
+    knoten
+}
+
+fn sequenz(knoten: &SequenzKnoten) -> String {
+    let mut text = knoten.links.join(", ");
+    if !text.is_empty() {
+        text.push(' ');
+    }
+    text.push('⊢');
+    if !knoten.rechts.is_empty() {
+        text.push(' ');
+        text.push_str(&knoten.rechts.join(", "));
+    }
+    text
+}
+
+/// Ersetzt die Junktoren der UTF-Schreibweise durch die LaTeX-Befehle.
+fn latex(text: &str) -> String {
+    let mut latex = String::new();
+    for zeichen in text.chars() {
+        match zeichen {
+            '¬' => latex.push_str("\\neg "),
+            '⋀' => latex.push_str("\\land "),
+            '⋁' => latex.push_str("\\lor "),
+            '⊕' => latex.push_str("\\oplus "),
+            '↑' => latex.push_str("\\uparrow "),
+            '↓' => latex.push_str("\\downarrow "),
+            '→' => latex.push_str("\\to "),
+            '↔' => latex.push_str("\\leftrightarrow "),
+            '⊤' => latex.push_str("\\top "),
+            '⊥' => latex.push_str("\\bot "),
+            '⊢' => latex.push_str("\\vdash "),
+            '_' => latex.push_str("\\_"),
+            ' ' if latex.ends_with(' ') => {}
+            zeichen => latex.push(zeichen),
+        }
+    }
+    latex.trim_end().to_string()
+}
+
+fn schreibe_latex(knoten: &SequenzKnoten, ausgabe: &mut String) {
+    let regel = match &knoten.regel {
+        Some(regel) => regel,
+        None => {
+            ausgabe.push_str(&format!("\\AxiomC{{${}$}}\n", latex(&sequenz(knoten))));
+            return;
+        }
+    };
+    if knoten.praemissen.is_empty() {
+        ausgabe.push_str("\\AxiomC{}\n");
+    }
+    for praemisse in &knoten.praemissen {
+        schreibe_latex(praemisse, ausgabe);
+    }
+    let schluss = match knoten.praemissen.len() {
+        2 => "BinaryInfC",
+        _ => "UnaryInfC",
+    };
+    let (junktor, seite) = regel.split_at(regel.len() - 1);
+    let name = if regel == "Ax" || regel == "Def" {
+        String::from(regel)
+    } else {
+        format!("${}${}", latex(junktor), seite)
+    };
+    ausgabe.push_str(&format!("\\RightLabel{{\\scriptsize {}}}\n", name));
+    ausgabe.push_str(&format!("\\{}{{${}$}}\n", schluss, latex(&sequenz(knoten))));
+}
+
+fn schreibe(knoten: &SequenzKnoten, f: &mut Formatter<'_>, erstes: &str, weitere: &str) -> std::fmt::Result {
+    write!(f, "{}{}", erstes, sequenz(knoten))?;
+    match (&knoten.regel, &knoten.gegenmodell) {
+        (Some(regel), _) => writeln!(f, "    ({})", regel)?,
+        (None, Some(gegenmodell)) => writeln!(f, "    ○ {}", werte_to_string(gegenmodell))?,
+        (None, None) => writeln!(f)?,
+    }
+    for (i, praemisse) in knoten.praemissen.iter().enumerate() {
+        if i + 1 == knoten.praemissen.len() {
+            schreibe(praemisse, f, &format!("{}└─ ", weitere), &format!("{}   ", weitere))?;
+        } else {
+            schreibe(praemisse, f, &format!("{}├─ ", weitere), &format!("{}│  ", weitere))?;
+        }
+    }
+    Ok(())
+}
+
+/// Schreibt die Herleitung als Baum, die Wurzel ist die gesuchte Sequenz und darunter stehen ihre Prämissen.
+/// Hinter jeder Sequenz steht die angewendete Regel, fehlschlagende Blätter enden mit ○ und ihrem Gegenmodell.
+impl Display for Sequenzbeweis {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        schreibe(&self.wurzel, f, "", "")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use crate::aussagen::parsing::parse_function;
+    use crate::aussagen::sequenzen::Sequenzbeweis;
+    use crate::aussagen::structures::FormelKontext;
+
+    #[test]
+    fn beweisbar_test() {
+        let kontext = FormelKontext::new();
+        let implikation = parse_function("A -> B").expect("Fehler bei parse");
+        let a = parse_function("A").expect("Fehler bei parse");
+        let b = parse_function("B").expect("Fehler bei parse");
+        let beweis = Sequenzbeweis::new(&kontext, &[&implikation, &a], &[&b]);
+        assert!(beweis.beweisbar);
+        assert_eq!(beweis.gegenmodell(), None);
+        assert_eq!(
+            beweis.to_string(),
+            "(A → B), A ⊢ B    (→L)\n├─ A ⊢ B, A    (Ax)\n└─ A, B ⊢ B    (Ax)\n"
+        );
+        assert_eq!(
+            beweis.to_latex(),
+            "\\begin{prooftree}\n\
+             \\AxiomC{}\n\\RightLabel{\\scriptsize Ax}\n\\UnaryInfC{$A \\vdash B, A$}\n\
+             \\AxiomC{}\n\\RightLabel{\\scriptsize Ax}\n\\UnaryInfC{$A, B \\vdash B$}\n\
+             \\RightLabel{\\scriptsize $\\to$L}\n\\BinaryInfC{$(A \\to B), A \\vdash B$}\n\
+             \\end{prooftree}"
+        );
+
+        let funktionen = [
+            "(A <-> B) -> (A ^ B | (A -> B))",
+            "-(A & B) <-> (-A | -B)",
+            "(A !& B) <-> -(A & B)",
+            "((A !| B) !| C) <-> ((A | B) & -C)",
+            "(A ^ B ^ C) <-> ((A <-> B) <-> C)",
+        ];
+        for funktion in funktionen {
+            let funktion = parse_function(funktion).expect("Fehler bei parse");
+            assert!(Sequenzbeweis::new(&kontext, &[], &[&funktion]).beweisbar, "{}", funktion);
+        }
+    }
+
+    #[test]
+    fn gegenmodell_test() {
+        let mut kontext = FormelKontext::new();
+        kontext
+            .funktionen
+            .insert(String::from("phi"), *parse_function("A | B").expect("Fehler bei parse"));
+        let phi = parse_function("phi").expect("Fehler bei parse");
+        let a = parse_function("A").expect("Fehler bei parse");
+        let beweis = Sequenzbeweis::new(&kontext, &[&phi], &[&a]);
+        assert!(!beweis.beweisbar);
+        assert_eq!(
+            beweis.gegenmodell(),
+            Some(&HashMap::from([(String::from("A"), false), (String::from("B"), true)]))
+        );
+        assert_eq!(
+            beweis.to_string(),
+            "phi ⊢ A    (Def)\n└─ (A ⋁ B) ⊢ A    (⋁L)\n   ├─ A ⊢ A    (Ax)\n   └─ B ⊢ A    ○ A = false, B = true\n"
+        );
+        assert!(beweis.to_latex().contains("\\AxiomC{$B \\vdash A$}"));
+    }
+}
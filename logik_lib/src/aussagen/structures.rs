@@ -1,6 +1,7 @@
 use std::collections::{HashMap, HashSet};
 use std::fmt::{Display, Formatter};
 
+
 #[derive(Debug, PartialEq, Eq, Hash)]
 pub enum AussagenFunktion {
     VARIABEL(String),
@@ -9,6 +10,11 @@ pub enum AussagenFunktion {
     NOT(Box<AussagenFunktion>),
     AND(Vec<Box<AussagenFunktion>>),
     OR(Vec<Box<AussagenFunktion>>),
+    XOR(Vec<Box<AussagenFunktion>>),
+    NAND(Vec<Box<AussagenFunktion>>),
+    NOR(Vec<Box<AussagenFunktion>>),
+    IMPLIKATION(Box<AussagenFunktion>, Box<AussagenFunktion>),
+    BIIMPLIKATION(Box<AussagenFunktion>, Box<AussagenFunktion>),
 }
 
 impl Display for AussagenFunktion {
@@ -18,7 +24,7 @@ impl Display for AussagenFunktion {
 }
 
 impl AussagenFunktion {
-    pub fn get_keys<'a>(&'a self, kontext: &'a FormelKontext) -> HashSet<&String> {
+    pub fn get_keys<'a>(&'a self, kontext: &'a FormelKontext) -> HashSet<&'a String> {
         match self {
             AussagenFunktion::VARIABEL(key) => {
                 if kontext.contains_funktion(key) {
@@ -28,13 +34,23 @@ impl AussagenFunktion {
             }
             AussagenFunktion::TOP() | AussagenFunktion::BOTTOM() => HashSet::new(),
             AussagenFunktion::NOT(funktion) => funktion.get_keys(kontext),
-            AussagenFunktion::AND(funktion) | AussagenFunktion::OR(funktion) => {
+            AussagenFunktion::AND(funktion)
+            | AussagenFunktion::OR(funktion)
+            | AussagenFunktion::XOR(funktion)
+            | AussagenFunktion::NAND(funktion)
+            | AussagenFunktion::NOR(funktion) => {
                 let mut set = HashSet::new();
                 for ele in funktion {
                     set.extend(&ele.get_keys(kontext));
                 }
                 set
             }
+            AussagenFunktion::IMPLIKATION(links, rechts)
+            | AussagenFunktion::BIIMPLIKATION(links, rechts) => {
+                let mut set = links.get_keys(kontext);
+                set.extend(&rechts.get_keys(kontext));
+                set
+            }
         }
     }
 
@@ -73,6 +89,33 @@ impl AussagenFunktion {
                 }
                 res
             }
+            AussagenFunktion::XOR(funktion) => {
+                let mut res = false;
+                for ele in funktion {
+                    res ^= ele.result(kontext, belegung, default);
+                }
+                res
+            }
+            AussagenFunktion::NAND(funktion) => {
+                let mut res = true;
+                for ele in funktion {
+                    res &= ele.result(kontext, belegung, default);
+                }
+                !res
+            }
+            AussagenFunktion::NOR(funktion) => {
+                let mut res = false;
+                for ele in funktion {
+                    res |= ele.result(kontext, belegung, default);
+                }
+                !res
+            }
+            AussagenFunktion::IMPLIKATION(links, rechts) => {
+                !links.result(kontext, belegung, default) || rechts.result(kontext, belegung, default)
+            }
+            AussagenFunktion::BIIMPLIKATION(links, rechts) => {
+                links.result(kontext, belegung, default) == rechts.result(kontext, belegung, default)
+            }
         }
     }
 
@@ -82,28 +125,17 @@ impl AussagenFunktion {
             AussagenFunktion::TOP() => String::from("t"),
             AussagenFunktion::BOTTOM() => String::from("f"),
             AussagenFunktion::NOT(funktion) => format!("-{}", funktion.to_ascii_string()),
-            AussagenFunktion::AND(funktion) => format!("({})", {
-                let mut s = String::new();
-                for ele in funktion {
-                    if s.is_empty() {
-                        s = ele.to_ascii_string();
-                    } else {
-                        s = format!("{} & {}", s, ele.to_ascii_string());
-                    }
-                }
-                s
-            }),
-            AussagenFunktion::OR(funktion) => format!("({})", {
-                let mut s = String::new();
-                for ele in funktion {
-                    if s.is_empty() {
-                        s = ele.to_ascii_string();
-                    } else {
-                        s = format!("{} | {}", s, ele.to_ascii_string());
-                    }
-                }
-                s
-            }),
+            AussagenFunktion::AND(funktion) => verbinde(funktion, " & ", false),
+            AussagenFunktion::OR(funktion) => verbinde(funktion, " | ", false),
+            AussagenFunktion::XOR(funktion) => verbinde(funktion, " ^ ", false),
+            AussagenFunktion::NAND(funktion) => verbinde(funktion, " !& ", false),
+            AussagenFunktion::NOR(funktion) => verbinde(funktion, " !| ", false),
+            AussagenFunktion::IMPLIKATION(links, rechts) => {
+                format!("({} -> {})", links.to_ascii_string(), rechts.to_ascii_string())
+            }
+            AussagenFunktion::BIIMPLIKATION(links, rechts) => {
+                format!("({} <-> {})", links.to_ascii_string(), rechts.to_ascii_string())
+            }
         }
     }
     pub fn to_utf_string(&self) -> String {
@@ -112,30 +144,37 @@ impl AussagenFunktion {
             AussagenFunktion::TOP() => String::from("⊤"),
             AussagenFunktion::BOTTOM() => String::from("⊥"),
             AussagenFunktion::NOT(funktion) => format!("¬{}", funktion.to_utf_string()),
-            AussagenFunktion::AND(funktion) => format!("({})", {
-                let mut s = String::new();
-                for ele in funktion {
-                    if s.is_empty() {
-                        s = ele.to_utf_string();
-                    } else {
-                        s = format!("{} ⋀ {}", s, ele.to_utf_string());
-                    }
-                }
-                s
-            }),
-            AussagenFunktion::OR(funktion) => format!("({})", {
-                let mut s = String::new();
-                for ele in funktion {
-                    if s.is_empty() {
-                        s = ele.to_utf_string();
-                    } else {
-                        s = format!("{} ⋁ {}", s, ele.to_utf_string());
-                    }
-                }
-                s
-            }),
+            AussagenFunktion::AND(funktion) => verbinde(funktion, " ⋀ ", true),
+            AussagenFunktion::OR(funktion) => verbinde(funktion, " ⋁ ", true),
+            AussagenFunktion::XOR(funktion) => verbinde(funktion, " ⊕ ", true),
+            AussagenFunktion::NAND(funktion) => verbinde(funktion, " ↑ ", true),
+            AussagenFunktion::NOR(funktion) => verbinde(funktion, " ↓ ", true),
+            AussagenFunktion::IMPLIKATION(links, rechts) => {
+                format!("({} → {})", links.to_utf_string(), rechts.to_utf_string())
+            }
+            AussagenFunktion::BIIMPLIKATION(links, rechts) => {
+                format!("({} ↔ {})", links.to_utf_string(), rechts.to_utf_string())
+            }
+        }
+    }
+}
+
+/// Verbindet die Teilformeln mit dem Operator und klammert das Ergebnis.
+fn verbinde(funktionen: &[Box<AussagenFunktion>], operator: &str, utf: bool) -> String {
+    let mut s = String::new();
+    for ele in funktionen {
+        let teil = if utf {
+            ele.to_utf_string()
+        } else {
+            ele.to_ascii_string()
+        };
+        if s.is_empty() {
+            s = teil;
+        } else {
+            s = format!("{}{}{}", s, operator, teil);
         }
     }
+    format!("({})", s)
 }
 
 impl Clone for AussagenFunktion {
@@ -147,6 +186,11 @@ impl Clone for AussagenFunktion {
             Self::NOT(arg0) => Self::NOT(arg0.clone()),
             Self::AND(arg0) => Self::AND(arg0.clone()),
             Self::OR(arg0) => Self::OR(arg0.clone()),
+            Self::XOR(arg0) => Self::XOR(arg0.clone()),
+            Self::NAND(arg0) => Self::NAND(arg0.clone()),
+            Self::NOR(arg0) => Self::NOR(arg0.clone()),
+            Self::IMPLIKATION(arg0, arg1) => Self::IMPLIKATION(arg0.clone(), arg1.clone()),
+            Self::BIIMPLIKATION(arg0, arg1) => Self::BIIMPLIKATION(arg0.clone(), arg1.clone()),
         }
     }
 }
@@ -158,6 +202,12 @@ pub struct FormelKontext {
     pub tabelle: Option<Wahrheitstabelle>,
 }
 
+impl Default for FormelKontext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl FormelKontext {
     pub fn contains_funktion(&self, key: &String) -> bool {
         self.funktionen.contains_key(key)
@@ -202,7 +252,7 @@ impl Display for Belegung {
         let mut new_line = false;
         for tupel in &self.ergebnisse {
             if new_line {
-                writeln!(f,"")?;
+                writeln!(f)?;
             }
             write!(f,"{} = {}",tupel.0, tupel.1)?;
             new_line = true;
@@ -212,6 +262,48 @@ impl Display for Belegung {
     }
 }
 
+/// Das Ergebnis einer Äquivalenzprüfung. Für jede Funktion, die nicht zur ersten äquivalent ist,
+/// gibt es eine Belegung, bei der sich die beiden unterscheiden.
+#[derive(Debug)]
+pub struct Aequivalenz {
+    pub gegenbeispiele: Vec<Belegung>,
+}
+
+impl Aequivalenz {
+    pub fn ist_aequivalent(&self) -> bool {
+        self.gegenbeispiele.is_empty()
+    }
+}
+
+impl Display for Aequivalenz {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.ist_aequivalent() {
+            return write!(f, "Äquivalent");
+        }
+        write!(f, "Nicht äquivalent")?;
+        for belegung in &self.gegenbeispiele {
+            write!(
+                f,
+                "\nGegenbeispiel: {}\n  {}",
+                werte_to_string(&belegung.werte),
+                werte_to_string(&belegung.ergebnisse)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Schreibt die Werte nach Variabelnamen sortiert, z.B. `A = true, B = false`.
+pub fn werte_to_string(werte: &HashMap<String, bool>) -> String {
+    let mut namen: Vec<&String> = werte.keys().collect();
+    namen.sort();
+    namen
+        .into_iter()
+        .map(|name| format!("{} = {}", name, werte[name]))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
 #[derive(Debug)]
 pub struct Wahrheitstabelle {
     pub belegungen: Vec<Belegung>,
@@ -228,12 +320,10 @@ impl Wahrheitstabelle {
 
 impl Display for Wahrheitstabelle {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let belegung = self.belegungen.get(0);
-        if belegung.is_none() {
-            ()
-        }
-        let belegung = belegung.unwrap();
-        println!("belegung: {:?}", belegung);
+        let belegung = match self.belegungen.first() {
+            Some(belegung) => belegung,
+            None => return Ok(()),
+        };
         for ele in &belegung.werte {
             write!(f, "  {}  |", ele.0)?;
         }
@@ -255,7 +345,7 @@ impl Display for Wahrheitstabelle {
             pattern.push('|');
             pattern_map.insert(ele.clone(), pattern);
         }
-        writeln!(f, "")?;
+        writeln!(f)?;
 
         let def = String::from(" {} |");
 
@@ -270,20 +360,14 @@ impl Display for Wahrheitstabelle {
             for erg in &self.reihenfolge {
                 let pattern = pattern_map.get(erg).unwrap_or(&def);
 
-                let filled_pattern;
-                let get = (&ele.ergebnisse).get(erg);
-                if get.is_none() {
-                    return Err(std::fmt::Error {});
-                } else {
-                    if *get.unwrap() {
-                        filled_pattern = pattern.replace("{}", "1");
-                    } else {
-                        filled_pattern = pattern.replace("{}", "0");
-                    }
-                }
+                let filled_pattern = match ele.ergebnisse.get(erg) {
+                    None => return Err(std::fmt::Error {}),
+                    Some(true) => pattern.replace("{}", "1"),
+                    Some(false) => pattern.replace("{}", "0"),
+                };
                 write!(f, "{}", filled_pattern)?;
             }
-            writeln!(f, "")?;
+            writeln!(f)?;
         }
 
         Ok(())
@@ -0,0 +1,300 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+use crate::aussagen::structures::{werte_to_string, AussagenFunktion, FormelKontext};
+
+/// Ein Knoten im Tableau mit den Formeln, die eine Regel dem Zweig hinzugefügt hat.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TableauKnoten {
+    pub formeln: Vec<String>,
+    /// Die Formel, deren α- oder β-Regel die Formeln dieses Knotens erzeugt hat.
+    pub aus: Option<String>,
+    pub kinder: Vec<TableauKnoten>,
+    /// Der Zweig endet hier, weil er eine Formel und ihre Negation (oder ⊥) enthält.
+    pub geschlossen: bool,
+    /// Ein offener, vollständig erweiterter Zweig. Die Literale auf ihm bilden ein Modell.
+    pub modell: Option<HashMap<String, bool>>,
+}
+
+/// Ein analytisches Tableau für eine Formel. Ist es geschlossen, ist die Formel unerfüllbar.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Tableau {
+    pub wurzel: TableauKnoten,
+    pub geschlossen: bool,
+}
+
+impl Tableau {
+    /// Erweitert die Formel zuerst mit den α-Regeln und verzweigt erst, wenn keine mehr anwendbar ist.
+    /// Verweise auf Funktionen aus dem Kontext werden dabei wie eine α-Regel aufgelöst.
+    pub fn new(kontext: &FormelKontext, funktion: &AussagenFunktion) -> Tableau {
+        let wurzel = baue(kontext, vec![funktion.clone()], None, &[], &[]);
+        Tableau {
+            geschlossen: ist_geschlossen(&wurzel),
+            wurzel,
+        }
+    }
+
+    /// Die Modelle aller offenen Zweige.
+    pub fn modelle(&self) -> Vec<&HashMap<String, bool>> {
+        let mut modelle = Vec::new();
+        sammle_modelle(&self.wurzel, &mut modelle);
+        modelle
+    }
+}
+
+fn ist_geschlossen(knoten: &TableauKnoten) -> bool {
+    knoten.geschlossen || (!knoten.kinder.is_empty() && knoten.kinder.iter().all(ist_geschlossen))
+}
+
+fn sammle_modelle<'a>(knoten: &'a TableauKnoten, modelle: &mut Vec<&'a HashMap<String, bool>>) {
+    if let Some(modell) = &knoten.modell {
+        modelle.push(modell);
+    }
+    for kind in &knoten.kinder {
+        sammle_modelle(kind, modelle);
+    }
+}
+
+fn nicht(funktion: &AussagenFunktion) -> AussagenFunktion {
+    AussagenFunktion::NOT(Box::new(funktion.clone()))
+}
+
+fn rest(funktionen: &[Box<AussagenFunktion>]) -> AussagenFunktion {
+    if funktionen.len() == 1 {
+        (*funktionen[0]).clone()
+    } else {
+        AussagenFunktion::XOR(funktionen.to_vec())
+    }
+}
+
+/// Die Zweige, die eine Regel aus der Formel erzeugt. Ein Zweig ist eine α-Regel, mehrere sind eine β-Regel.
+/// `None` für Literale, ⊤, ⊥ und ¬⊤, ¬⊥.
+fn regel(kontext: &FormelKontext, funktion: &AussagenFunktion) -> Option<Vec<Vec<AussagenFunktion>>> {
+    let alle = |funktionen: &[Box<AussagenFunktion>], negiert: bool| -> Vec<AussagenFunktion> {
+        funktionen
+            .iter()
+            .map(|teil| if negiert { nicht(teil) } else { (**teil).clone() })
+            .collect()
+    };
+    let einzeln = |funktionen: &[Box<AussagenFunktion>], negiert: bool| -> Vec<Vec<AussagenFunktion>> {
+        alle(funktionen, negiert).into_iter().map(|teil| vec![teil]).collect()
+    };
+    match funktion {
+        AussagenFunktion::VARIABEL(name) => kontext.funktionen.get(name).map(|referenz| vec![vec![referenz.clone()]]),
+        AussagenFunktion::TOP() | AussagenFunktion::BOTTOM() => None,
+        AussagenFunktion::AND(funktionen) => Some(vec![alle(funktionen, false)]),
+        AussagenFunktion::NOR(funktionen) => Some(vec![alle(funktionen, true)]),
+        AussagenFunktion::OR(funktionen) => Some(einzeln(funktionen, false)),
+        AussagenFunktion::NAND(funktionen) => Some(einzeln(funktionen, true)),
+        AussagenFunktion::IMPLIKATION(links, rechts) => Some(vec![vec![nicht(links)], vec![(**rechts).clone()]]),
+        AussagenFunktion::BIIMPLIKATION(links, rechts) => Some(vec![
+            vec![(**links).clone(), (**rechts).clone()],
+            vec![nicht(links), nicht(rechts)],
+        ]),
+        AussagenFunktion::XOR(funktionen) => match funktionen.as_slice() {
+            [] => None,
+            [einzige] => Some(vec![vec![(**einzige).clone()]]),
+            [erste, weitere @ ..] => Some(vec![
+                vec![(**erste).clone(), nicht(&rest(weitere))],
+                vec![nicht(erste), rest(weitere)],
+            ]),
+        },
+        AussagenFunktion::NOT(inner) => match &**inner {
+            AussagenFunktion::VARIABEL(name) => kontext
+                .funktionen
+                .get(name)
+                .map(|referenz| vec![vec![nicht(referenz)]]),
+            AussagenFunktion::TOP() | AussagenFunktion::BOTTOM() => None,
+            AussagenFunktion::NOT(doppelt) => Some(vec![vec![(**doppelt).clone()]]),
+            AussagenFunktion::OR(funktionen) => Some(vec![alle(funktionen, true)]),
+            AussagenFunktion::NAND(funktionen) => Some(vec![alle(funktionen, false)]),
+            AussagenFunktion::AND(funktionen) => Some(einzeln(funktionen, true)),
+            AussagenFunktion::NOR(funktionen) => Some(einzeln(funktionen, false)),
+            AussagenFunktion::IMPLIKATION(links, rechts) => Some(vec![vec![(**links).clone(), nicht(rechts)]]),
+            AussagenFunktion::BIIMPLIKATION(links, rechts) => Some(vec![
+                vec![(**links).clone(), nicht(rechts)],
+                vec![nicht(links), (**rechts).clone()],
+            ]),
+            AussagenFunktion::XOR(funktionen) => match funktionen.as_slice() {
+                [] => None,
+                [einzige] => Some(vec![vec![nicht(einzige)]]),
+                [erste, weitere @ ..] => Some(vec![
+                    vec![(**erste).clone(), rest(weitere)],
+                    vec![nicht(erste), nicht(&rest(weitere))],
+                ]),
+            },
+        },
+    }
+}
+
+/// Ob die neue Formel den Zweig schließt.
+fn schliesst(funktion: &AussagenFunktion, zweig: &[AussagenFunktion]) -> bool {
+    match funktion {
+        AussagenFunktion::BOTTOM() => true,
+        AussagenFunktion::NOT(inner) => **inner == AussagenFunktion::TOP() || zweig.contains(inner),
+        _ => zweig.contains(&nicht(funktion)),
+    }
+}
+
+/// Baut den Knoten mit den neuen `formeln` und erweitert den Zweig darunter.
+/// `zweig` sind alle Formeln über dem Knoten, `offen` die davon noch nicht erweiterten.
+fn baue(
+    kontext: &FormelKontext,
+    formeln: Vec<AussagenFunktion>,
+    aus: Option<&AussagenFunktion>,
+    zweig: &[AussagenFunktion],
+    offen: &[AussagenFunktion],
+) -> TableauKnoten {
+    let mut knoten = TableauKnoten {
+        formeln: formeln.iter().map(|funktion| funktion.to_utf_string()).collect(),
+        aus: aus.map(|funktion| funktion.to_utf_string()),
+        kinder: Vec::new(),
+        geschlossen: false,
+        modell: None,
+    };
+    let mut zweig = zweig.to_vec();
+    for funktion in &formeln {
+        if schliesst(funktion, &zweig) {
+            knoten.geschlossen = true;
+            return knoten;
+        }
+        zweig.push(funktion.clone());
+    }
+    let mut offen = offen.to_vec();
+    offen.extend(formeln);
+
+    let mut regeln: Vec<(usize, Vec<Vec<AussagenFunktion>>)> = offen
+        .iter()
+        .enumerate()
+        .filter_map(|(i, funktion)| regel(kontext, funktion).map(|zweige| (i, zweige)))
+        .collect();
+    // α-Regeln vor β-Regeln, damit das Tableau möglichst wenig verzweigt.
+    regeln.sort_by_key(|(_, zweige)| zweige.len() > 1);
+    match regeln.into_iter().next() {
+        Some((i, zweige)) => {
+            let erweitert = offen.remove(i);
+            knoten.kinder = zweige
+                .into_iter()
+                .map(|formeln| baue(kontext, formeln, Some(&erweitert), &zweig, &offen))
+                .collect();
+        }
+        None => {
+            // Verweise auf Funktionen aus dem Kontext sind schon aufgelöst und gehören nicht ins Modell.
+            let modell = zweig
+                .iter()
+                .filter_map(|funktion| match funktion {
+                    AussagenFunktion::VARIABEL(name) if !kontext.contains_funktion(name) => Some((name.clone(), true)),
+                    AussagenFunktion::NOT(inner) => match &**inner {
+                        AussagenFunktion::VARIABEL(name) if !kontext.contains_funktion(name) => {
+                            Some((name.clone(), false))
+                        }
+                        _ => None,
+                    },
+                    _ => None,
+                })
+                .collect();
+            knoten.modell = Some(modell);
+        }
+    }
+    knoten
+}
+
+fn schreibe(knoten: &TableauKnoten, f: &mut Formatter<'_>, erstes: &str, weitere: &str) -> std::fmt::Result {
+    for (i, formel) in knoten.formeln.iter().enumerate() {
+        writeln!(f, "{}{}", if i == 0 { erstes } else { weitere }, formel)?;
+    }
+    if knoten.geschlossen {
+        writeln!(f, "{}×", weitere)?;
+    }
+    if let Some(modell) = &knoten.modell {
+        writeln!(f, "{}○ {}", weitere, werte_to_string(modell))?;
+    }
+    match knoten.kinder.as_slice() {
+        [kind] => schreibe(kind, f, weitere, weitere),
+        kinder => {
+            for (i, kind) in kinder.iter().enumerate() {
+                if i + 1 == kinder.len() {
+                    schreibe(kind, f, &format!("{}└─ ", weitere), &format!("{}   ", weitere))?;
+                } else {
+                    schreibe(kind, f, &format!("{}├─ ", weitere), &format!("{}│  ", weitere))?;
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Schreibt das Tableau als eingerückten Baum. Geschlossene Zweige enden mit ×, offene mit ○ und ihrem Modell.
+impl Display for Tableau {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        schreibe(&self.wurzel, f, "", "")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::aussagen::parsing::parse_function;
+    use crate::aussagen::structures::{werte_to_string, FormelKontext};
+    use crate::aussagen::tableau::Tableau;
+
+    #[test]
+    fn geschlossen_test() {
+        let kontext = FormelKontext::new();
+        let funktion = parse_function("-((A -> B) -> (-B -> -A))").expect("Fehler bei parse");
+        let tableau = Tableau::new(&kontext, &funktion);
+        assert!(tableau.geschlossen);
+        assert!(tableau.modelle().is_empty());
+
+        let funktion = parse_function("(A <-> B) & (A ^ B)").expect("Fehler bei parse");
+        assert!(Tableau::new(&kontext, &funktion).geschlossen);
+    }
+
+    #[test]
+    fn offen_test() {
+        let kontext = FormelKontext::new();
+        let funktion = parse_function("(A | B) & -A").expect("Fehler bei parse");
+        let tableau = Tableau::new(&kontext, &funktion);
+        assert!(!tableau.geschlossen);
+        let modelle = tableau.modelle();
+        assert_eq!(modelle.len(), 1);
+        assert!(funktion.result(&kontext, modelle[0], false));
+        assert_eq!(
+            format!("{}", tableau),
+            concat!(
+                "((A ⋁ B) ⋀ ¬A)\n",
+                "(A ⋁ B)\n",
+                "¬A\n",
+                "├─ A\n",
+                "│  ×\n",
+                "└─ B\n",
+                "   ○ A = false, B = true\n",
+            )
+        );
+        assert_eq!(tableau.wurzel.kinder[0].aus.as_deref(), Some("((A ⋁ B) ⋀ ¬A)"));
+    }
+
+    #[test]
+    fn kontext_test() {
+        let mut kontext = FormelKontext::new();
+        kontext
+            .funktionen
+            .insert(String::from("phi"), *parse_function("A !| B").expect("Fehler bei parse"));
+        let funktion = parse_function("phi & (A | B)").expect("Fehler bei parse");
+        assert!(Tableau::new(&kontext, &funktion).geschlossen);
+        let funktion = parse_function("-phi").expect("Fehler bei parse");
+        let tableau = Tableau::new(&kontext, &funktion);
+        assert_eq!(tableau.modelle().len(), 2);
+        assert!(tableau.modelle().iter().all(|modell| !modell.contains_key("phi")));
+
+        kontext
+            .funktionen
+            .insert(String::from("psi"), *parse_function("A | B").expect("Fehler bei parse"));
+        let funktion = parse_function("psi & -A").expect("Fehler bei parse");
+        let tableau = Tableau::new(&kontext, &funktion);
+        let modelle = tableau.modelle();
+        assert_eq!(modelle.len(), 1);
+        assert_eq!(werte_to_string(modelle[0]), "A = false, B = true");
+    }
+}
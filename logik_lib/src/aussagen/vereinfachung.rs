@@ -0,0 +1,399 @@
+use std::fmt::{Display, Formatter};
+
+use crate::aussagen::structures::AussagenFunktion;
+
+/// Die Gesetze der booleschen Algebra, die [`AussagenFunktion::vereinfache`] anwendet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gesetz {
+    /// Ersetzt ↑, ↓, ⊕, → oder ↔ durch ⋀, ⋁ und ¬.
+    Definition(&'static str),
+    DoppelteNegation,
+    DeMorgan,
+    Assoziativitaet,
+    /// x ⋀ ⊥ = ⊥ und x ⋁ ⊤ = ⊤
+    Dominanz,
+    /// x ⋀ ¬x = ⊥, x ⋁ ¬x = ⊤, ¬⊤ = ⊥ und ¬⊥ = ⊤
+    Komplement,
+    /// x ⋀ ⊤ = x und x ⋁ ⊥ = x
+    Neutralitaet,
+    Idempotenz,
+    /// x ⋁ (x ⋀ y) = x und x ⋀ (x ⋁ y) = x
+    Absorption,
+    /// Ausklammern: (x ⋀ y) ⋁ (x ⋀ z) = x ⋀ (y ⋁ z) und dual
+    Distributivitaet,
+}
+
+impl Display for Gesetz {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Gesetz::Definition(junktor) => write!(f, "Definition von {}", junktor),
+            Gesetz::DoppelteNegation => write!(f, "Doppelte Negation"),
+            Gesetz::DeMorgan => write!(f, "De Morgan"),
+            Gesetz::Assoziativitaet => write!(f, "Assoziativität"),
+            Gesetz::Dominanz => write!(f, "Dominanz"),
+            Gesetz::Komplement => write!(f, "Komplement"),
+            Gesetz::Neutralitaet => write!(f, "Neutralität"),
+            Gesetz::Idempotenz => write!(f, "Idempotenz"),
+            Gesetz::Absorption => write!(f, "Absorption"),
+            Gesetz::Distributivitaet => write!(f, "Distributivität"),
+        }
+    }
+}
+
+/// Ein Schritt der Vereinfachung: `vorher` ist die umgeformte Teilformel, `formel` die ganze Formel danach.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Umformung {
+    pub gesetz: Gesetz,
+    pub vorher: AussagenFunktion,
+    pub nachher: AussagenFunktion,
+    pub formel: AussagenFunktion,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vereinfachung {
+    pub ausgang: AussagenFunktion,
+    pub schritte: Vec<Umformung>,
+}
+
+impl Vereinfachung {
+    pub fn ergebnis(&self) -> &AussagenFunktion {
+        self.schritte.last().map_or(&self.ausgang, |umformung| &umformung.formel)
+    }
+}
+
+impl Display for Vereinfachung {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "  {}", self.ausgang)?;
+        for umformung in &self.schritte {
+            writeln!(
+                f,
+                "= {}    [{}: {} ⟹ {}]",
+                umformung.formel, umformung.gesetz, umformung.vorher, umformung.nachher
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl AussagenFunktion {
+    /// Vereinfacht die Formel Schritt für Schritt mit den Gesetzen der booleschen Algebra, bis keines mehr anwendbar ist.
+    /// Es wird immer die innerste, am weitesten links stehende Teilformel umgeformt.
+    /// Verweise auf andere Funktionen werden nicht aufgelöst, sondern wie Variabeln behandelt.
+    pub fn vereinfache(&self) -> Vereinfachung {
+        let mut vereinfachung = Vereinfachung {
+            ausgang: self.clone(),
+            schritte: Vec::new(),
+        };
+        while let Some((formel, gesetz, vorher, nachher)) = schritt(vereinfachung.ergebnis()) {
+            vereinfachung.schritte.push(Umformung {
+                gesetz,
+                vorher,
+                nachher,
+                formel,
+            });
+        }
+        vereinfachung
+    }
+}
+
+type Schritt = (AussagenFunktion, Gesetz, AussagenFunktion, AussagenFunktion);
+
+/// Wendet ein Gesetz auf die innerste passende Teilformel an und gibt die neue Formel zurück.
+fn schritt(funktion: &AussagenFunktion) -> Option<Schritt> {
+    let innen = match funktion {
+        AussagenFunktion::VARIABEL(_) | AussagenFunktion::TOP() | AussagenFunktion::BOTTOM() => None,
+        AussagenFunktion::NOT(inner) => schritt(inner).map(|(neu, gesetz, vorher, nachher)| {
+            (AussagenFunktion::NOT(Box::new(neu)), gesetz, vorher, nachher)
+        }),
+        AussagenFunktion::AND(funktionen)
+        | AussagenFunktion::OR(funktionen)
+        | AussagenFunktion::XOR(funktionen)
+        | AussagenFunktion::NAND(funktionen)
+        | AussagenFunktion::NOR(funktionen) => funktionen.iter().enumerate().find_map(|(i, teil)| {
+            schritt(teil).map(|(neu, gesetz, vorher, nachher)| {
+                let mut operanden = funktionen.clone();
+                *operanden[i] = neu;
+                (gleiche_art(funktion, operanden), gesetz, vorher, nachher)
+            })
+        }),
+        AussagenFunktion::IMPLIKATION(links, rechts) => schritt(links)
+            .map(|(neu, gesetz, vorher, nachher)| {
+                (AussagenFunktion::IMPLIKATION(Box::new(neu), rechts.clone()), gesetz, vorher, nachher)
+            })
+            .or_else(|| {
+                schritt(rechts).map(|(neu, gesetz, vorher, nachher)| {
+                    (AussagenFunktion::IMPLIKATION(links.clone(), Box::new(neu)), gesetz, vorher, nachher)
+                })
+            }),
+        AussagenFunktion::BIIMPLIKATION(links, rechts) => schritt(links)
+            .map(|(neu, gesetz, vorher, nachher)| {
+                (AussagenFunktion::BIIMPLIKATION(Box::new(neu), rechts.clone()), gesetz, vorher, nachher)
+            })
+            .or_else(|| {
+                schritt(rechts).map(|(neu, gesetz, vorher, nachher)| {
+                    (AussagenFunktion::BIIMPLIKATION(links.clone(), Box::new(neu)), gesetz, vorher, nachher)
+                })
+            }),
+    };
+    innen.or_else(|| regel(funktion).map(|(gesetz, neu)| (neu.clone(), gesetz, funktion.clone(), neu)))
+}
+
+/// Baut eine Funktion derselben n-stelligen Art mit neuen Operanden.
+// Die Operanden werden unverändert in die Variante übernommen, die sie als Vec<Box<_>> speichert.
+#[allow(clippy::vec_box)]
+fn gleiche_art(funktion: &AussagenFunktion, operanden: Vec<Box<AussagenFunktion>>) -> AussagenFunktion {
+    match funktion {
+        AussagenFunktion::AND(_) => AussagenFunktion::AND(operanden),
+        AussagenFunktion::OR(_) => AussagenFunktion::OR(operanden),
+        AussagenFunktion::XOR(_) => AussagenFunktion::XOR(operanden),
+        AussagenFunktion::NAND(_) => AussagenFunktion::NAND(operanden),
+        AussagenFunktion::NOR(_) => AussagenFunktion::NOR(operanden),
+        _ => panic!("{} ist nicht n-stellig", funktion),
+    }
+}
+
+fn nicht(funktion: &AussagenFunktion) -> Box<AussagenFunktion> {
+    Box::new(AussagenFunktion::NOT(Box::new(funktion.clone())))
+}
+
+/// Verknüpft die Operanden mit ⋀ oder ⋁. Ein einzelner Operand bleibt allein, keiner ergibt das neutrale Element.
+#[allow(clippy::vec_box)]
+fn verknuepfe(oder: bool, mut operanden: Vec<Box<AussagenFunktion>>) -> AussagenFunktion {
+    match operanden.len() {
+        0 if oder => AussagenFunktion::BOTTOM(),
+        0 => AussagenFunktion::TOP(),
+        1 => *operanden.pop().unwrap(),
+        _ if oder => AussagenFunktion::OR(operanden),
+        _ => AussagenFunktion::AND(operanden),
+    }
+}
+
+/// Die Operanden, wenn die Funktion dieselbe Art (⋀ oder ⋁) hat, sonst die Funktion selbst.
+fn teile(funktion: &AussagenFunktion, oder: bool) -> Vec<&AussagenFunktion> {
+    match (funktion, oder) {
+        (AussagenFunktion::AND(funktionen), false) | (AussagenFunktion::OR(funktionen), true) => {
+            funktionen.iter().map(|teil| &**teil).collect()
+        }
+        _ => vec![funktion],
+    }
+}
+
+/// Sucht ein Gesetz, das direkt auf die Funktion passt.
+fn regel(funktion: &AussagenFunktion) -> Option<(Gesetz, AussagenFunktion)> {
+    match funktion {
+        AussagenFunktion::VARIABEL(_) | AussagenFunktion::TOP() | AussagenFunktion::BOTTOM() => None,
+        AussagenFunktion::NOT(inner) => match &**inner {
+            AussagenFunktion::NOT(doppelt) => Some((Gesetz::DoppelteNegation, (**doppelt).clone())),
+            AussagenFunktion::TOP() => Some((Gesetz::Komplement, AussagenFunktion::BOTTOM())),
+            AussagenFunktion::BOTTOM() => Some((Gesetz::Komplement, AussagenFunktion::TOP())),
+            AussagenFunktion::AND(funktionen) => Some((
+                Gesetz::DeMorgan,
+                AussagenFunktion::OR(funktionen.iter().map(|teil| nicht(teil)).collect()),
+            )),
+            AussagenFunktion::OR(funktionen) => Some((
+                Gesetz::DeMorgan,
+                AussagenFunktion::AND(funktionen.iter().map(|teil| nicht(teil)).collect()),
+            )),
+            _ => None,
+        },
+        AussagenFunktion::NAND(funktionen) => Some((
+            Gesetz::Definition("↑"),
+            AussagenFunktion::NOT(Box::new(AussagenFunktion::AND(funktionen.clone()))),
+        )),
+        AussagenFunktion::NOR(funktionen) => Some((
+            Gesetz::Definition("↓"),
+            AussagenFunktion::NOT(Box::new(AussagenFunktion::OR(funktionen.clone()))),
+        )),
+        AussagenFunktion::XOR(funktionen) => Some((Gesetz::Definition("⊕"), xor_definition(funktionen))),
+        AussagenFunktion::IMPLIKATION(links, rechts) => Some((
+            Gesetz::Definition("→"),
+            AussagenFunktion::OR(vec![nicht(links), rechts.clone()]),
+        )),
+        AussagenFunktion::BIIMPLIKATION(links, rechts) => Some((
+            Gesetz::Definition("↔"),
+            AussagenFunktion::OR(vec![
+                Box::new(AussagenFunktion::AND(vec![links.clone(), rechts.clone()])),
+                Box::new(AussagenFunktion::AND(vec![nicht(links), nicht(rechts)])),
+            ]),
+        )),
+        AussagenFunktion::AND(funktionen) => verband_regel(funktionen, false),
+        AussagenFunktion::OR(funktionen) => verband_regel(funktionen, true),
+    }
+}
+
+/// a ⊕ R = (a ⋀ ¬R) ⋁ (¬a ⋀ R)
+fn xor_definition(funktionen: &[Box<AussagenFunktion>]) -> AussagenFunktion {
+    match funktionen {
+        [] => AussagenFunktion::BOTTOM(),
+        [funktion] => (**funktion).clone(),
+        [erste, rest @ ..] => {
+            let rest = if rest.len() == 1 {
+                rest[0].clone()
+            } else {
+                Box::new(AussagenFunktion::XOR(rest.to_vec()))
+            };
+            AussagenFunktion::OR(vec![
+                Box::new(AussagenFunktion::AND(vec![erste.clone(), nicht(&rest)])),
+                Box::new(AussagenFunktion::AND(vec![nicht(erste), rest])),
+            ])
+        }
+    }
+}
+
+/// Die Gesetze für ⋀ (`oder == false`) und ⋁ (`oder == true`), die Regeln sind jeweils dual.
+fn verband_regel(funktionen: &[Box<AussagenFunktion>], oder: bool) -> Option<(Gesetz, AussagenFunktion)> {
+    let (neutral, dominant) = if oder {
+        (AussagenFunktion::BOTTOM(), AussagenFunktion::TOP())
+    } else {
+        (AussagenFunktion::TOP(), AussagenFunktion::BOTTOM())
+    };
+
+    if funktionen.iter().any(|teil| teile(teil, oder).len() > 1) {
+        let operanden = funktionen
+            .iter()
+            .flat_map(|teil| teile(teil, oder))
+            .map(|teil| Box::new(teil.clone()))
+            .collect();
+        return Some((Gesetz::Assoziativitaet, verknuepfe(oder, operanden)));
+    }
+
+    if funktionen.iter().any(|teil| **teil == dominant) {
+        return Some((Gesetz::Dominanz, dominant));
+    }
+
+    let komplement = funktionen.iter().any(|teil| match &**teil {
+        AussagenFunktion::NOT(inner) => funktionen.contains(inner),
+        _ => false,
+    });
+    if komplement {
+        return Some((Gesetz::Komplement, dominant));
+    }
+
+    if funktionen.iter().any(|teil| **teil == neutral) {
+        let operanden = funktionen.iter().filter(|teil| ***teil != neutral).cloned().collect();
+        return Some((Gesetz::Neutralitaet, verknuepfe(oder, operanden)));
+    }
+
+    for (i, teil) in funktionen.iter().enumerate() {
+        if funktionen[..i].contains(teil) {
+            let mut operanden = funktionen.to_vec();
+            operanden.remove(i);
+            return Some((Gesetz::Idempotenz, verknuepfe(oder, operanden)));
+        }
+    }
+
+    // Ein Operand wird absorbiert, wenn alle Teile eines anderen Operanden in ihm vorkommen.
+    for (j, groesser) in funktionen.iter().enumerate() {
+        let groessere_teile = teile(groesser, !oder);
+        let absorbiert = funktionen.iter().enumerate().any(|(i, kleiner)| {
+            i != j && teile(kleiner, !oder).iter().all(|teil| groessere_teile.contains(teil))
+        });
+        if absorbiert {
+            let mut operanden = funktionen.to_vec();
+            operanden.remove(j);
+            return Some((Gesetz::Absorption, verknuepfe(oder, operanden)));
+        }
+    }
+
+    // Ausklammern eines gemeinsamen Teils zweier Operanden.
+    for i in 0..funktionen.len() {
+        for j in i + 1..funktionen.len() {
+            let links = teile(&funktionen[i], !oder);
+            let rechts = teile(&funktionen[j], !oder);
+            if links.len() < 2 || rechts.len() < 2 {
+                continue;
+            }
+            if let Some(gemeinsam) = links.iter().find(|teil| rechts.contains(teil)) {
+                let ohne = |teile: &[&AussagenFunktion]| {
+                    verknuepfe(
+                        !oder,
+                        teile
+                            .iter()
+                            .filter(|teil| *teil != gemeinsam)
+                            .map(|teil| Box::new((*teil).clone()))
+                            .collect(),
+                    )
+                };
+                let ausgeklammert = verknuepfe(
+                    !oder,
+                    vec![
+                        Box::new((*gemeinsam).clone()),
+                        Box::new(verknuepfe(oder, vec![Box::new(ohne(&links)), Box::new(ohne(&rechts))])),
+                    ],
+                );
+                let mut operanden = funktionen.to_vec();
+                *operanden[i] = ausgeklammert;
+                operanden.remove(j);
+                return Some((Gesetz::Distributivitaet, verknuepfe(oder, operanden)));
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use crate::aussagen::is_aequivalent;
+    use crate::aussagen::parsing::parse_function;
+    use crate::aussagen::structures::{AussagenFunktion, FormelKontext};
+    use crate::aussagen::vereinfachung::Gesetz;
+
+    fn vereinfacht(formel: &str) -> String {
+        parse_function(formel)
+            .expect("Fehler bei parse")
+            .vereinfache()
+            .ergebnis()
+            .to_ascii_string()
+    }
+
+    #[test]
+    fn gesetze_test() {
+        assert_eq!(vereinfacht("A & A"), "A");
+        assert_eq!(vereinfacht("A | (A & B)"), "A");
+        assert_eq!(vereinfacht("A & (A | B)"), "A");
+        assert_eq!(vereinfacht("-(A & B)"), "(-A | -B)");
+        assert_eq!(vereinfacht("--A"), "A");
+        assert_eq!(vereinfacht("A & -A"), "f");
+        assert_eq!(vereinfacht("A | -A | B"), "t");
+        assert_eq!(vereinfacht("A & t"), "A");
+        assert_eq!(vereinfacht("A | f | B"), "(A | B)");
+        assert_eq!(vereinfacht("B & f"), "f");
+        assert_eq!(vereinfacht("(A & B) | (A & -B)"), "A");
+        assert_eq!(vereinfacht("A -> A"), "t");
+    }
+
+    #[test]
+    fn schritte_test() {
+        let vereinfachung = parse_function("-(-A | f)").expect("Fehler bei parse").vereinfache();
+        let gesetze: Vec<Gesetz> = vereinfachung.schritte.iter().map(|umformung| umformung.gesetz).collect();
+        assert_eq!(gesetze, vec![Gesetz::Neutralitaet, Gesetz::DoppelteNegation]);
+        assert_eq!(vereinfachung.schritte[0].vorher.to_ascii_string(), "(-A | f)");
+        assert_eq!(vereinfachung.schritte[0].formel.to_ascii_string(), "--A");
+        assert_eq!(*vereinfachung.ergebnis(), AussagenFunktion::VARIABEL(String::from("A")));
+        assert_eq!(
+            format!("{}", vereinfachung),
+            "  ¬(¬A ⋁ ⊥)\n= ¬¬A    [Neutralität: (¬A ⋁ ⊥) ⟹ ¬A]\n= A    [Doppelte Negation: ¬¬A ⟹ A]\n"
+        );
+    }
+
+    #[test]
+    fn aequivalenz_test() {
+        for formel in [
+            "(A ^ B ^ C) & (A | C)",
+            "(A <-> B) | (A !& -C)",
+            "-(A !| B) -> (C & A)",
+            "(A | B) & (A | C) & -(B & C)",
+        ] {
+            let mut kontext = FormelKontext::new();
+            let funktion = parse_function(formel).expect("Fehler bei parse");
+            let ergebnis = funktion.vereinfache().ergebnis().clone();
+            kontext.funktionen.insert(String::from("phi"), *funktion);
+            kontext.funktionen.insert(String::from("psi"), ergebnis);
+            let funktionen = vec![
+                kontext.funktionen.get("phi").unwrap(),
+                kontext.funktionen.get("psi").unwrap(),
+            ];
+            assert!(is_aequivalent(&kontext, funktionen), "{} ≢ {}", formel, kontext.funktionen["psi"]);
+        }
+    }
+}
@@ -0,0 +1,263 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{Display, Formatter};
+
+use crate::aussagen::klauseln::{Klausel, KlauselMenge, Literal};
+use crate::aussagen::structures::{werte_to_string, AussagenFunktion, FormelKontext};
+
+/// Das Ergebnis des 2-SAT-Verfahrens.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum ZweiSat {
+    Erfuellbar(HashMap<String, bool>),
+    /// Ein Kreis `x → ... → ¬x → ... → x` im Implikationsgraphen, der mit `x` beginnt und endet.
+    /// Leer, wenn die Klauselmenge die leere Klausel enthält.
+    Widerspruch(Vec<Literal>),
+}
+
+impl Display for ZweiSat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ZweiSat::Erfuellbar(modell) => write!(f, "Erfüllbar: {}", werte_to_string(modell)),
+            ZweiSat::Widerspruch(kreis) if kreis.is_empty() => {
+                write!(f, "Unerfüllbar: Die Klauselmenge enthält die leere Klausel")
+            }
+            ZweiSat::Widerspruch(kreis) => {
+                let kreis: Vec<String> = kreis.iter().map(|literal| literal.to_string()).collect();
+                write!(f, "Unerfüllbar, Widerspruch: {}", kreis.join(" → "))
+            }
+        }
+    }
+}
+
+impl KlauselMenge {
+    /// Ob jede Klausel höchstens zwei Literale hat.
+    pub fn ist_zwei_knf(&self) -> bool {
+        self.klauseln.iter().all(|klausel| klausel.literale.len() <= 2)
+    }
+}
+
+impl AussagenFunktion {
+    /// Die Klauselmenge der Funktion, wenn sie schon die Form einer 2-KNF hat, also eine Konjunktion von
+    /// Disjunktionen mit höchstens zwei Literalen. Siehe [`Self::to_klauselform`].
+    pub fn to_zwei_knf(&self, kontext: &FormelKontext) -> Option<KlauselMenge> {
+        self.to_klauselform(kontext, 2)
+    }
+}
+
+/// Löst eine 2-KNF über ihren Implikationsgraphen in linearer Zeit. Jede Klausel `a ⋁ b` ergibt die Kanten
+/// `¬a → b` und `¬b → a`. Die Klauselmenge ist genau dann unerfüllbar, wenn eine Variable in derselben starken
+/// Zusammenhangskomponente liegt wie ihre Negation. `None`, wenn die Klauselmenge keine 2-KNF ist.
+pub fn loese_zwei_sat(menge: &KlauselMenge) -> Option<ZweiSat> {
+    if !menge.ist_zwei_knf() {
+        return None;
+    }
+    if menge.klauseln.iter().any(Klausel::ist_leer) {
+        return Some(ZweiSat::Widerspruch(Vec::new()));
+    }
+    let variablen = menge.variablen();
+    let index: HashMap<&str, usize> = variablen
+        .iter()
+        .enumerate()
+        .map(|(i, name)| (name.as_str(), i))
+        .collect();
+    // Wie im SAT-Solver ist ein Literal 2 * Variable + negiert.
+    let knoten = |literal: &Literal| 2 * index[literal.name.as_str()] + literal.negiert as usize;
+
+    let mut kanten: Vec<Vec<usize>> = vec![Vec::new(); 2 * variablen.len()];
+    for klausel in &menge.klauseln {
+        let a = knoten(&klausel.literale[0]);
+        let b = klausel.literale.get(1).map(knoten).unwrap_or(a);
+        kanten[a ^ 1].push(b);
+        if a != b {
+            kanten[b ^ 1].push(a);
+        }
+    }
+
+    let komponente = komponenten(&kanten);
+    let literal = |knoten: usize| Literal {
+        name: variablen[knoten / 2].clone(),
+        negiert: knoten % 2 == 1,
+    };
+    if let Some(variable) = (0..variablen.len()).find(|v| komponente[2 * v] == komponente[2 * v + 1]) {
+        let mut kreis = pfad(&kanten, 2 * variable, 2 * variable + 1);
+        kreis.extend(pfad(&kanten, 2 * variable + 1, 2 * variable).into_iter().skip(1));
+        return Some(ZweiSat::Widerspruch(kreis.into_iter().map(literal).collect()));
+    }
+
+    // Tarjan schließt die Komponenten in umgekehrter topologischer Reihenfolge ab. Ein Literal, dessen Komponente
+    // vor der seiner Negation abgeschlossen wurde, kann wahr sein, ohne dass daraus seine Negation folgt.
+    let modell = variablen
+        .iter()
+        .enumerate()
+        .map(|(v, name)| (name.clone(), komponente[2 * v] < komponente[2 * v + 1]))
+        .collect();
+    Some(ZweiSat::Erfuellbar(modell))
+}
+
+/// Die starken Zusammenhangskomponenten nach Tarjan, ohne Rekursion. Gibt für jeden Knoten die Nummer seiner
+/// Komponente in der Reihenfolge zurück, in der sie abgeschlossen wurden.
+fn komponenten(kanten: &[Vec<usize>]) -> Vec<usize> {
+    let n = kanten.len();
+    let mut index = vec![usize::MAX; n];
+    let mut lowlink = vec![0; n];
+    let mut auf_stack = vec![false; n];
+    let mut stack = Vec::new();
+    let mut komponente = vec![usize::MAX; n];
+    let mut zaehler = 0;
+    let mut anzahl = 0;
+
+    for start in 0..n {
+        if index[start] != usize::MAX {
+            continue;
+        }
+        let mut aufrufe = vec![(start, 0)];
+        index[start] = zaehler;
+        lowlink[start] = zaehler;
+        zaehler += 1;
+        stack.push(start);
+        auf_stack[start] = true;
+        while let Some(&(knoten, kante)) = aufrufe.last() {
+            if let Some(&ziel) = kanten[knoten].get(kante) {
+                aufrufe.last_mut().unwrap().1 += 1;
+                if index[ziel] == usize::MAX {
+                    index[ziel] = zaehler;
+                    lowlink[ziel] = zaehler;
+                    zaehler += 1;
+                    stack.push(ziel);
+                    auf_stack[ziel] = true;
+                    aufrufe.push((ziel, 0));
+                } else if auf_stack[ziel] {
+                    lowlink[knoten] = lowlink[knoten].min(index[ziel]);
+                }
+                continue;
+            }
+            aufrufe.pop();
+            if let Some(&(eltern, _)) = aufrufe.last() {
+                lowlink[eltern] = lowlink[eltern].min(lowlink[knoten]);
+            }
+            if lowlink[knoten] == index[knoten] {
+                while let Some(w) = stack.pop() {
+                    auf_stack[w] = false;
+                    komponente[w] = anzahl;
+                    if w == knoten {
+                        break;
+                    }
+                }
+                anzahl += 1;
+            }
+        }
+    }
+    komponente
+}
+
+/// Ein kürzester Pfad von `von` nach `nach` mit Breitensuche, einschließlich beider Enden.
+/// Wird nur innerhalb einer starken Zusammenhangskomponente aufgerufen, daher gibt es ihn immer.
+fn pfad(kanten: &[Vec<usize>], von: usize, nach: usize) -> Vec<usize> {
+    let mut vorgaenger = vec![usize::MAX; kanten.len()];
+    vorgaenger[von] = von;
+    let mut warteschlange = VecDeque::from([von]);
+    while let Some(knoten) = warteschlange.pop_front() {
+        if knoten == nach {
+            break;
+        }
+        for &ziel in &kanten[knoten] {
+            if vorgaenger[ziel] == usize::MAX {
+                vorgaenger[ziel] = knoten;
+                warteschlange.push_back(ziel);
+            }
+        }
+    }
+    let mut pfad = vec![nach];
+    let mut knoten = nach;
+    while knoten != von {
+        knoten = vorgaenger[knoten];
+        pfad.push(knoten);
+    }
+    pfad.reverse();
+    pfad
+}
+
+#[cfg(test)]
+mod test {
+    use crate::aussagen::klauseln::KlauselMenge;
+    use crate::aussagen::parsing::parse_function;
+    use crate::aussagen::structures::FormelKontext;
+    use crate::aussagen::zwei_sat::{loese_zwei_sat, ZweiSat};
+
+    fn loese(formel: &str) -> (KlauselMenge, ZweiSat) {
+        let kontext = FormelKontext::new();
+        let funktion = parse_function(formel).expect("Fehler bei parse");
+        let menge = funktion.to_zwei_knf(&kontext).expect("Keine 2-KNF");
+        let ergebnis = loese_zwei_sat(&menge).expect("Keine 2-KNF");
+        (menge, ergebnis)
+    }
+
+    #[test]
+    fn erkennung_test() {
+        let kontext = FormelKontext::new();
+        let zwei_knf = ["(A | B) & (-A | C) & -C", "(A -> B) & -(A & B) & t", "A | f | B", "-(A | B | C)"];
+        for formel in zwei_knf {
+            let funktion = parse_function(formel).expect("Fehler bei parse");
+            assert!(funktion.to_zwei_knf(&kontext).is_some(), "{} sollte eine 2-KNF sein", formel);
+        }
+        let keine = ["A | B | C", "(A & B) | C", "A <-> B", "A ^ B", "A !& B", "-(A & B & C)"];
+        for formel in keine {
+            let funktion = parse_function(formel).expect("Fehler bei parse");
+            assert!(funktion.to_zwei_knf(&kontext).is_none(), "{} sollte keine 2-KNF sein", formel);
+        }
+    }
+
+    #[test]
+    fn erfuellbar_test() {
+        let (menge, ergebnis) = loese("(A | B) & (-A | C) & (-B | -C) & (C | D) & -D");
+        let modell = match ergebnis {
+            ZweiSat::Erfuellbar(modell) => modell,
+            ZweiSat::Widerspruch(kreis) => panic!("Sollte erfüllbar sein: {:?}", kreis),
+        };
+        for klausel in &menge.klauseln {
+            assert!(
+                klausel.literale.iter().any(|literal| modell[&literal.name] != literal.negiert),
+                "Klausel {} nicht erfüllt",
+                klausel
+            );
+        }
+    }
+
+    #[test]
+    fn widerspruch_test() {
+        let (menge, ergebnis) = loese("(A | B) & (-A | B) & (A | -B) & (-A | -B)");
+        let kreis = match ergebnis {
+            ZweiSat::Erfuellbar(modell) => panic!("Sollte unerfüllbar sein: {:?}", modell),
+            ZweiSat::Widerspruch(kreis) => kreis,
+        };
+        assert_eq!(kreis.first(), kreis.last());
+        assert!(kreis.contains(&kreis[0].negation()), "Der Kreis muss die Negation enthalten");
+        for schritt in kreis.windows(2) {
+            assert!(
+                menge.klauseln.iter().any(|klausel| klausel.literale.contains(&schritt[0].negation())
+                    && (klausel.literale.contains(&schritt[1]) || klausel.literale.len() == 1)),
+                "{} → {} ist keine Kante",
+                schritt[0],
+                schritt[1]
+            );
+        }
+
+        let (_, ergebnis) = loese("A & (A -> B) & -B");
+        assert_eq!(ergebnis.to_string(), "Unerfüllbar, Widerspruch: A → B → ¬B → ¬A → A");
+        let (_, ergebnis) = loese("A & f");
+        assert_eq!(ergebnis, ZweiSat::Widerspruch(Vec::new()));
+    }
+
+    #[test]
+    fn kette_test() {
+        let mut formel = String::from("X0");
+        for i in 0..2000 {
+            formel.push_str(&format!(" & (X{} -> X{})", i, i + 1));
+        }
+        let (_, ergebnis) = loese(&formel);
+        match ergebnis {
+            ZweiSat::Erfuellbar(modell) => assert!(modell.values().all(|wert| *wert)),
+            ZweiSat::Widerspruch(kreis) => panic!("Sollte erfüllbar sein: {:?}", kreis),
+        }
+    }
+}
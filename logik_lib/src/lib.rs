@@ -9,14 +9,12 @@ pub fn add(left: usize, right: usize) -> usize {
 
 #[cfg(test)]
 mod tests {
-    use slab_tree::Tree;
     use std::collections::HashMap;
 
     use crate::aussagen::structures::AussagenFunktion::{self, *};
     use crate::aussagen::structures::FormelKontext;
-    use crate::aussagen::parsing::ParseOption::VARIABLE;
-    use crate::aussagen::get_wahrheitstabelle;
-    use crate::aussagen::parsing::{parse_function, Parsed, ParseOption};
+    use crate::aussagen::{anzahl_modelle, get_wahrheitstabelle, ist_erfuellbar, pruefe_aequivalenz};
+    use crate::aussagen::parsing::parse_function;
 
     use super::*;
 
@@ -91,12 +89,12 @@ mod tests {
     }
 
     fn test_parse(formel: &str) {
-        let funktion = parse_function(&formel).expect("couldnt parse");
+        let funktion = parse_function(formel).expect("couldnt parse");
         assert_eq!(funktion.to_utf_string(), formel);
     }
 
     fn test_parse_ascii(formel: &str) {
-        let funktion = parse_function(&formel).expect("couldnt parse");
+        let funktion = parse_function(formel).expect("couldnt parse");
         assert_eq!(funktion.to_ascii_string(), formel);
     }
 
@@ -113,6 +111,7 @@ mod tests {
         let kontext = FormelKontext {
             funktionen: HashMap::from([(String::from("phi1"), funktion.clone())]),
             belegung: vec![],
+            tabelle: None,
         };
         assert!(!funktion.result(&kontext, &belegung, false))
     }
@@ -140,28 +139,6 @@ mod tests {
         test_parse("⊤");
     }
 
-    #[test]
-    fn swich_parent() {
-        let mut tree: Tree<Parsed> = Tree::new();
-        tree.set_root(Parsed {
-            option: VARIABLE(String::from("1"), false),
-        });
-
-        let mut current = tree.root_mut().unwrap();
-        let _new_id = &current
-            .append(Parsed {
-                option: VARIABLE(String::from("2"), false),
-            })
-            .node_id();
-        current.data().option = ParseOption::AND();
-
-        assert_eq!(tree.root().unwrap().data().option, ParseOption::AND());
-        assert_eq!(
-            tree.root().unwrap().first_child().unwrap().data().option,
-            VARIABLE(String::from("2"), false)
-        );
-    }
-
     #[test]
     fn teste_einzel_variable() {
         let eingabe = String::from("A");
@@ -196,4 +173,121 @@ mod tests {
         println!("{} {}", &formel, &parsed);
         assert_eq!(formel, *parsed);
     }
+
+    #[test]
+    fn teste_weitere_junktoren() {
+        test_parse("(A → B)");
+        test_parse("(A ↔ B)");
+        test_parse("(A ⊕ B ⊕ C)");
+        test_parse("(A ↑ B)");
+        test_parse("(A ↓ ¬B)");
+        test_parse_ascii("(A -> -B)");
+        test_parse_ascii("(A <-> (B & C))");
+        test_parse_ascii("(A ^ B)");
+        test_parse_ascii("(A !& B)");
+        test_parse_ascii("(A !| B)");
+
+        let parsed = parse_function("(A -> B -> C)").expect("Fehler bei parse");
+        let formel = IMPLIKATION(
+            Box::from(VARIABEL(String::from("A"))),
+            Box::from(IMPLIKATION(
+                Box::from(VARIABEL(String::from("B"))),
+                Box::from(VARIABEL(String::from("C"))),
+            )),
+        );
+        assert_eq!(formel, *parsed);
+    }
+
+    #[test]
+    fn teste_weitere_junktoren_result() {
+        let kontext = FormelKontext::new();
+        let werte = |a: bool, b: bool| {
+            HashMap::from([(String::from("A"), a), (String::from("B"), b)])
+        };
+        for (a, b) in [(false, false), (false, true), (true, false), (true, true)] {
+            let belegung = werte(a, b);
+            let ergebnis = |formel: &str| {
+                parse_function(formel)
+                    .expect("Fehler bei parse")
+                    .result(&kontext, &belegung, false)
+            };
+            assert_eq!(ergebnis("(A -> B)"), !a || b);
+            assert_eq!(ergebnis("(A <-> B)"), a == b);
+            assert_eq!(ergebnis("(A ^ B)"), a != b);
+            assert_eq!(ergebnis("(A !& B)"), !(a && b));
+            assert_eq!(ergebnis("(A !| B)"), !(a || b));
+        }
+    }
+
+    #[test]
+    fn teste_gegenbeispiel() {
+        let mut kontext = FormelKontext::new();
+        for (name, formel) in [("phi", "A -> B"), ("psi", "A -> B"), ("chi", "B -> A")] {
+            kontext
+                .funktionen
+                .insert(String::from(name), *parse_function(formel).expect("Fehler bei parse"));
+        }
+        let phi = kontext.funktionen.get("phi").unwrap();
+        let psi = kontext.funktionen.get("psi").unwrap();
+        let chi = kontext.funktionen.get("chi").unwrap();
+
+        // phi und psi haben dieselbe Struktur, das Gegenbeispiel muss trotzdem beide aufführen.
+        assert!(pruefe_aequivalenz(&kontext, vec![("phi", phi), ("psi", psi)]).ist_aequivalent());
+        let aequivalenz = pruefe_aequivalenz(&kontext, vec![("phi", phi), ("psi", psi), ("chi", chi)]);
+        assert_eq!(aequivalenz.gegenbeispiele.len(), 1);
+        let belegung = &aequivalenz.gegenbeispiele[0];
+        assert_eq!(belegung.werte.len(), 2);
+        assert_eq!(belegung.ergebnisse.len(), 3);
+        assert_ne!(belegung.ergebnisse["phi"], belegung.ergebnisse["chi"]);
+        assert_eq!(belegung.ergebnisse["phi"], belegung.ergebnisse["psi"]);
+        assert!(format!("{}", aequivalenz).starts_with("Nicht äquivalent\nGegenbeispiel: A = "));
+
+        // Funktionen, die nicht im Kontext stehen, werden unter dem übergebenen Namen geführt.
+        let formel = parse_function("A & B").expect("Fehler bei parse");
+        let aequivalenz = pruefe_aequivalenz(&kontext, vec![("phi", phi), ("A & B", &formel)]);
+        assert!(aequivalenz.gegenbeispiele[0].ergebnisse.contains_key("A & B"));
+    }
+
+    #[test]
+    fn teste_anzahl_modelle() {
+        let mut kontext = FormelKontext::new();
+        kontext
+            .funktionen
+            .insert(String::from("phi"), *parse_function("A -> B").expect("Fehler bei parse"));
+        let funktion = parse_function("phi & (C | t)").expect("Fehler bei parse");
+        assert_eq!(anzahl_modelle(&kontext, &funktion), Some(6));
+
+        let namen: Vec<String> = (0..100).map(|i| format!("x{}", i)).collect();
+        let funktion = parse_function(&namen.join(" ^ ")).expect("Fehler bei parse");
+        assert_eq!(anzahl_modelle(&kontext, &funktion), Some(1 << 99));
+    }
+
+    #[test]
+    fn teste_erfuellbar() {
+        let mut kontext = FormelKontext::new();
+        kontext
+            .funktionen
+            .insert(String::from("phi"), *parse_function("(A -> B) & (B -> C)").expect("Fehler bei parse"));
+        // phi & A & -D ist eine 2-KNF, mit (C | D | E) nicht mehr.
+        for formel in ["phi & A & -D", "phi & A & -D & (C | D | E)"] {
+            let funktion = parse_function(formel).expect("Fehler bei parse");
+            let modell = ist_erfuellbar(&kontext, &funktion).expect("Sollte erfüllbar sein");
+            assert!(funktion.result(&kontext, &modell, false), "{} ist nicht erfüllt", formel);
+        }
+        let funktion = parse_function("phi & A & -C").expect("Fehler bei parse");
+        assert_eq!(ist_erfuellbar(&kontext, &funktion), None, "Sollte unerfüllbar sein");
+    }
+
+    #[test]
+    fn teste_erfuellbar_xor_kette() {
+        // Die 2-KNF-Erkennung darf ⊕ und ↔ nicht ausmultiplizieren, sonst wächst die Prüfung exponentiell.
+        let kontext = FormelKontext::new();
+        for operator in ["^", "<->"] {
+            let variablen: Vec<String> = (0..200).map(|i| format!("X{}", i)).collect();
+            let formel = format!("({}) & X0", variablen.join(&format!(") {} (", operator)));
+            let funktion = parse_function(&formel).expect("Fehler bei parse");
+            let modell = ist_erfuellbar(&kontext, &funktion).expect("Sollte erfüllbar sein");
+            assert!(funktion.result(&kontext, &modell, false), "{} ist nicht erfüllt", formel);
+        }
+    }
 }
@@ -6,9 +6,15 @@ use derive_more::Display;
 
 use crate::aussagen::structures::FormelKontext;
 use crate::aussagen::parsing::ParseError;
+use crate::script::beweis::{beweis, beweis_pruefen};
+use crate::script::folgert::folgert;
+use crate::script::minimiere::minimiere;
 use crate::script::print::print;
+use crate::script::sequenz::sequenz;
 use crate::script::set::set;
 use crate::script::tabelle::tabelle;
+use crate::script::vollstaendig::vollstaendig;
+use crate::script::vereinfache::vereinfache;
 
 
 
@@ -24,27 +30,50 @@ pub enum ScriptAction {
     },
     #[display(fmt = "Tabelle generiert")]
     GenerateTabelle(),
+    /// Eine Zeile eines `BEWEIS`-Blocks, die erst bei `ENDE` geprüft wird.
+    #[display(fmt = "")]
+    Gepuffert(),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Display)]
 pub enum ScriptError {
+    #[display(fmt = "Befehl nicht implementiert: {}", _0)]
     FunctionTypeNotImplemented(String),
+    #[display(fmt = "Falsche Syntax: {}", _0)]
     WrongSyntax(String),
+    #[display(fmt = "{}\n{}", "_1.markiere(_0)", _1)]
     ParseNotPossible(String, ParseError),
+    #[display(fmt = "Tabelle wurde nicht generiert: {}", string)]
     TabelleNotGenerated{
         string: String
     },
+    #[display(fmt = "Funktion nicht gefunden: {}", _0)]
     FunktionNotFound(String),
+    #[display(fmt = "KV-Diagramm nicht möglich: {}", _0)]
+    KarnaughNotPossible(String),
+    #[display(fmt = "Minimierung nicht möglich: {}", _0)]
+    MinimierungNotPossible(String),
+    #[display(fmt = "{}", _0)]
+    KeineHornFormel(String),
+    #[display(fmt = "Post-Klassen nicht bestimmbar: {}", _0)]
+    PostNotPossible(String),
+    #[display(fmt = "Beweis fehlerhaft:\n{}", _0)]
+    BeweisNotCorrect(String),
 }
 
 impl ScriptError {
     pub fn get_string(&self) -> &String {
         match self {
-            ScriptError::FunctionTypeNotImplemented(string) => &string,
-            ScriptError::WrongSyntax(string) => &string,
-            ScriptError::ParseNotPossible(string, _) => &string,
-            ScriptError::TabelleNotGenerated {string} => &string,
-            ScriptError::FunktionNotFound(string) => &string,
+            ScriptError::FunctionTypeNotImplemented(string) => string,
+            ScriptError::WrongSyntax(string) => string,
+            ScriptError::ParseNotPossible(string, _) => string,
+            ScriptError::TabelleNotGenerated {string} => string,
+            ScriptError::FunktionNotFound(string) => string,
+            ScriptError::KarnaughNotPossible(string) => string,
+            ScriptError::MinimierungNotPossible(string) => string,
+            ScriptError::KeineHornFormel(string) => string,
+            ScriptError::PostNotPossible(string) => string,
+            ScriptError::BeweisNotCorrect(string) => string,
         }
     }
     pub fn set_string(&mut self, new_string: String) {
@@ -53,12 +82,62 @@ impl ScriptError {
             ScriptError::WrongSyntax(string) => *string = new_string,
             ScriptError::ParseNotPossible(string, _) => *string = new_string,
             ScriptError::TabelleNotGenerated{string} => *string = new_string,
-            ScriptError::FunktionNotFound(string) => *string = new_string
+            ScriptError::FunktionNotFound(string) => *string = new_string,
+            ScriptError::KarnaughNotPossible(string) => *string = new_string,
+            ScriptError::MinimierungNotPossible(string) => *string = new_string,
+            ScriptError::KeineHornFormel(string) => *string = new_string,
+            ScriptError::PostNotPossible(string) => *string = new_string,
+            ScriptError::BeweisNotCorrect(string) => *string = new_string,
+        }
+    }
+}
+
+/// Führt ein Script Zeile für Zeile aus und merkt sich dabei, was über eine Zeile hinausgeht, wie einen offenen
+/// `BEWEIS`-Block. Alle anderen Zeilen gehen an [`parse_line`].
+#[derive(Debug, Default)]
+pub struct Script {
+    /// Die Zeilen eines offenen `BEWEIS`-Blocks, `None` wenn kein Beweis begonnen wurde.
+    beweis: Option<Vec<String>>,
+}
+
+impl Script {
+    pub fn new() -> Script {
+        Script::default()
+    }
+
+    pub fn parse_line(&mut self, line: &str, kontext: &mut FormelKontext) -> Result<ScriptAction, ScriptError> {
+        if let Some(zeilen) = self.beweis.as_mut() {
+            if !line.trim().eq_ignore_ascii_case("ENDE") {
+                zeilen.push(String::from(line));
+                return Ok(ScriptAction::Gepuffert());
+            }
+            let zeilen = self.beweis.take().unwrap_or_default();
+            return beatify_error(line, beweis_pruefen(zeilen));
+        }
+        let mut iterator = line.split_whitespace();
+        match iterator.next() {
+            Some("beweis") | Some("BEWEIS") => {
+                let result = beweis(iterator);
+                if result.is_ok() {
+                    self.beweis = Some(Vec::new());
+                }
+                beatify_error(line, result)
+            }
+            _ => parse_line(line, kontext),
+        }
+    }
+
+    /// Schließt das Script ab. Ein `BEWEIS`-Block ohne `ENDE` ist ein Fehler, seine Zeilen werden verworfen.
+    pub fn beende(&mut self) -> Result<(), ScriptError> {
+        match self.beweis.take() {
+            Some(_) => Err(ScriptError::BeweisNotCorrect(String::from("ENDE fehlt"))),
+            None => Ok(()),
         }
     }
 }
 
 /// begins to parse the line of text. Delegates the everything but the first word to other functions.
+/// Blöcke über mehrere Zeilen wie `BEWEIS` gehen nur über [`Script`].
 pub fn parse_line(line: &str, kontext: &mut FormelKontext) -> Result<ScriptAction, ScriptError> {
     //Key Word Match
     let mut iterator: SplitWhitespace = line.split_whitespace();
@@ -70,6 +149,11 @@ pub fn parse_line(line: &str, kontext: &mut FormelKontext) -> Result<ScriptActio
         "set" | "SET" => set(iterator, kontext),
         "print" | "PRINT" => print(iterator, kontext),
         "tabelle" | "TABELLE" => tabelle(iterator,kontext),
+        "minimiere" | "MINIMIERE" => minimiere(iterator, kontext),
+        "vereinfache" | "VEREINFACHE" => vereinfache(iterator, kontext),
+        "folgert" | "FOLGERT" => folgert(iterator, kontext),
+        "sequenz" | "SEQUENZ" => sequenz(iterator, kontext),
+        "vollstaendig" | "VOLLSTAENDIG" | "VOLLSTÄNDIG" => vollstaendig(iterator, kontext),
         s => Err(ScriptError::FunctionTypeNotImplemented(String::from(s))),
     };
 
@@ -100,6 +184,7 @@ fn get_rest(iterator: &mut SplitWhitespace) -> Result<String, ScriptError> {
     let mut rest = String::from(next.unwrap());
 
     for ele in iterator {
+        rest.push(' ');
         rest.push_str(ele);
     }
     Ok(rest)
@@ -149,7 +234,7 @@ mod set {
                 })
             }
             Err(parse_error) => Err(ScriptError::ParseNotPossible(
-                String::from(formel),
+                formel,
                 parse_error,
             )),
         }
@@ -161,11 +246,20 @@ mod set {
 mod print {
     use std::collections::HashMap;
     use std::str::SplitWhitespace;
-    use crate::aussagen::{get_belegung, is_aequivalent};
-
-    use crate::aussagen::structures::FormelKontext;
+    use crate::aussagen::{anzahl_modelle, get_belegung, pruefe_aequivalenz};
+    use crate::aussagen::karnaugh::KarnaughDiagramm;
+    use crate::aussagen::klassifikation::klassifiziere;
+    use crate::aussagen::gatter::{anzahl_gatter, Gattertyp, Schaltung};
+    use crate::aussagen::post::PostKlassen;
+    use crate::aussagen::tableau::Tableau;
+    use crate::aussagen::zwei_sat::loese_zwei_sat;
+    use crate::aussagen::minimierung::minimiere;
+
+    use crate::aussagen::structures::{AussagenFunktion, FormelKontext};
     use crate::script::ScriptAction::Print;
-    use crate::script::ScriptError::{FunktionNotFound, TabelleNotGenerated};
+    use crate::script::ScriptError::{
+        FunktionNotFound, KarnaughNotPossible, KeineHornFormel, PostNotPossible, TabelleNotGenerated,
+    };
 
     use super::{ScriptAction, ScriptError};
 
@@ -183,7 +277,24 @@ mod print {
             "Tabelle" => print_tabelle(kontext),
             "Belegung" | "BELEGUNG" => print_belegung(iterator, kontext),
             "aequivalenz" | "AEQUIVALENZ" => print_aequivalenz(iterator,kontext),
-            _ => return Err(ScriptError::WrongSyntax(String::new())),
+            "nnf" | "NNF" => print_nnf(iterator, kontext),
+            "knf" | "KNF" => print_knf(iterator, kontext, false),
+            "knf-tseitin" | "KNF-TSEITIN" => print_knf(iterator, kontext, true),
+            "klauseln" | "KLAUSELN" => print_klauseln(iterator, kontext),
+            "dnf" | "DNF" => print_dnf(iterator, kontext),
+            "kanonische-dnf" | "KANONISCHE-DNF" => print_kanonische_form(iterator, kontext, true),
+            "kanonische-knf" | "KANONISCHE-KNF" => print_kanonische_form(iterator, kontext, false),
+            "karnaugh" | "KARNAUGH" => print_karnaugh(iterator, kontext),
+            "klassifikation" | "KLASSIFIKATION" => print_klassifikation(iterator, kontext),
+            "anzahl" | "ANZAHL" => print_anzahl(iterator, kontext),
+            "resolution" | "RESOLUTION" => print_resolution(iterator, kontext),
+            "tableau" | "TABLEAU" => print_tableau(iterator, kontext),
+            "horn" | "HORN" => print_horn(iterator, kontext),
+            "2-sat" | "2-SAT" => print_zwei_sat(iterator, kontext),
+            "post" | "POST" => print_post(iterator, kontext),
+            "nand" | "NAND" => print_gatter(iterator, kontext, Gattertyp::Nand),
+            "nor" | "NOR" => print_gatter(iterator, kontext, Gattertyp::Nor),
+            _ => Err(ScriptError::WrongSyntax(String::new())),
         }
     }
 
@@ -198,13 +309,11 @@ mod print {
         }
         let next = next.unwrap();
 
-        let funktion = kontext.funktionen.get(&String::from(next));
-        if funktion.is_none() {
-            Err(ScriptError::WrongSyntax(String::from(next)))
-        } else {
-            Ok(Print {
-                ausgabe: funktion.unwrap().to_utf_string(),
-            })
+        match kontext.funktionen.get(&String::from(next)) {
+            None => Err(ScriptError::WrongSyntax(String::from(next))),
+            Some(funktion) => Ok(Print {
+                ausgabe: funktion.to_utf_string(),
+            }),
         }
     }
 
@@ -219,14 +328,243 @@ mod print {
         }
         let next = next.unwrap();
 
-        let funktion = kontext.funktionen.get(&String::from(next));
-        if funktion.is_none() {
-            Err(ScriptError::WrongSyntax(String::from(next)))
+        match kontext.funktionen.get(&String::from(next)) {
+            None => Err(ScriptError::WrongSyntax(String::from(next))),
+            Some(funktion) => Ok(Print {
+                ausgabe: funktion.to_ascii_string(),
+            }),
+        }
+    }
+
+    /// Liest den nächsten Namen und sucht die Funktion mit diesem Namen im Kontext.
+    fn get_funktion<'a>(
+        iterator: &mut SplitWhitespace,
+        kontext: &'a FormelKontext,
+    ) -> Result<&'a AussagenFunktion, ScriptError> {
+        let next = iterator.next();
+        if next.is_none() {
+            return Err(ScriptError::WrongSyntax(String::new()));
+        }
+        find_funktion(next.unwrap(), kontext)
+    }
+
+    fn find_funktion<'a>(
+        name: &str,
+        kontext: &'a FormelKontext,
+    ) -> Result<&'a AussagenFunktion, ScriptError> {
+        match kontext.funktionen.get(name) {
+            None => Err(FunktionNotFound(String::from(name))),
+            Some(funktion) => Ok(funktion),
+        }
+    }
+
+    fn print_nnf(
+        mut iterator: SplitWhitespace,
+        kontext: &mut FormelKontext,
+    ) -> Result<ScriptAction, ScriptError> {
+        let funktion = get_funktion(&mut iterator, kontext)?;
+        Ok(Print {
+            ausgabe: funktion.to_nnf(kontext).to_utf_string(),
+        })
+    }
+
+    fn print_knf(
+        mut iterator: SplitWhitespace,
+        kontext: &mut FormelKontext,
+        tseitin: bool,
+    ) -> Result<ScriptAction, ScriptError> {
+        let funktion = get_funktion(&mut iterator, kontext)?;
+        let knf = if tseitin {
+            funktion.to_knf_tseitin(kontext)
+        } else {
+            funktion.to_knf(kontext)
+        };
+        Ok(Print {
+            ausgabe: knf.to_funktion().to_utf_string(),
+        })
+    }
+
+    fn print_dnf(
+        mut iterator: SplitWhitespace,
+        kontext: &mut FormelKontext,
+    ) -> Result<ScriptAction, ScriptError> {
+        let funktion = get_funktion(&mut iterator, kontext)?;
+        Ok(Print {
+            ausgabe: funktion.to_dnf(kontext).to_utf_string(),
+        })
+    }
+
+    /// Gibt die kanonische DNF oder KNF aus, die aus den Wahrheitswerten der Funktion abgelesen wird.
+    fn print_kanonische_form(
+        mut iterator: SplitWhitespace,
+        kontext: &mut FormelKontext,
+        dnf: bool,
+    ) -> Result<ScriptAction, ScriptError> {
+        let funktion = get_funktion(&mut iterator, kontext)?;
+        let form = if dnf {
+            funktion.kanonische_dnf(kontext)
         } else {
-            Ok(Print {
-                ausgabe: funktion.unwrap().to_ascii_string(),
-            })
+            funktion.kanonische_knf(kontext)
         }
+        .map_err(|fehler| TabelleNotGenerated { string: fehler.to_string() })?;
+        Ok(Print {
+            ausgabe: form.to_utf_string(),
+        })
+    }
+
+    /// Gibt das KV-Diagramm aus. Mit `GRUPPEN` werden die Implikanten der Minimalform markiert.
+    fn print_karnaugh(
+        mut iterator: SplitWhitespace,
+        kontext: &mut FormelKontext,
+    ) -> Result<ScriptAction, ScriptError> {
+        let funktion = get_funktion(&mut iterator, kontext)?;
+        let mut diagramm = KarnaughDiagramm::new(kontext, funktion)
+            .map_err(|fehler| KarnaughNotPossible(fehler.to_string()))?;
+        match iterator.next() {
+            None => {}
+            Some("gruppen") | Some("GRUPPEN") => {
+                let minimierung = minimiere(kontext, funktion)
+                    .map_err(|fehler| KarnaughNotPossible(fehler.to_string()))?;
+                diagramm = diagramm
+                    .mit_gruppen(&minimierung)
+                    .map_err(|fehler| KarnaughNotPossible(fehler.to_string()))?;
+            }
+            Some(other) => return Err(ScriptError::WrongSyntax(String::from(other))),
+        }
+        Ok(Print {
+            ausgabe: format!("{}", diagramm),
+        })
+    }
+
+    fn print_klassifikation(
+        mut iterator: SplitWhitespace,
+        kontext: &mut FormelKontext,
+    ) -> Result<ScriptAction, ScriptError> {
+        let funktion = get_funktion(&mut iterator, kontext)?;
+        Ok(Print {
+            ausgabe: format!("{}", klassifiziere(kontext, funktion)),
+        })
+    }
+
+    fn print_anzahl(
+        mut iterator: SplitWhitespace,
+        kontext: &mut FormelKontext,
+    ) -> Result<ScriptAction, ScriptError> {
+        let funktion = get_funktion(&mut iterator, kontext)?;
+        let ausgabe = match anzahl_modelle(kontext, funktion) {
+            Some(anzahl) => format!("{}", anzahl),
+            None => String::from("Mindestens 2^128 Modelle"),
+        };
+        Ok(Print { ausgabe })
+    }
+
+    fn print_resolution(
+        mut iterator: SplitWhitespace,
+        kontext: &mut FormelKontext,
+    ) -> Result<ScriptAction, ScriptError> {
+        let funktion = get_funktion(&mut iterator, kontext)?;
+        let ausgabe = match funktion.widerlege(kontext) {
+            Some(beweis) => format!("{}", beweis),
+            None => String::from("Keine Widerlegung möglich, die Klauselmenge ist erfüllbar"),
+        };
+        Ok(Print { ausgabe })
+    }
+
+    /// `PRINT HORN <NAME> [<VARIABLEN>...]`: das minimale Modell und für jede Variable, ob sie folgt.
+    fn print_horn(
+        mut iterator: SplitWhitespace,
+        kontext: &mut FormelKontext,
+    ) -> Result<ScriptAction, ScriptError> {
+        let name = iterator.next().ok_or(ScriptError::WrongSyntax(String::new()))?;
+        let funktion = find_funktion(name, kontext)?;
+        let modell = funktion
+            .minimales_modell(kontext)
+            .map_err(|fehler| KeineHornFormel(fehler.to_string()))?;
+        let mut ausgabe = match &modell {
+            None => String::from("Unerfüllbar"),
+            Some(modell) => {
+                let mut wahr: Vec<&String> = modell.iter().filter(|(_, wert)| **wert).map(|(name, _)| name).collect();
+                wahr.sort();
+                let wahr: Vec<&str> = wahr.into_iter().map(String::as_str).collect();
+                format!("Minimales Modell: {{{}}}", wahr.join(", "))
+            }
+        };
+        for variable in iterator {
+            let folgt = match &modell {
+                None => true,
+                Some(modell) => modell.get(variable).copied().unwrap_or(false),
+            };
+            ausgabe.push_str(&format!("\n{} {} {}", name, if folgt { "⊨" } else { "⊭" }, variable));
+        }
+        Ok(Print { ausgabe })
+    }
+
+    fn print_zwei_sat(
+        mut iterator: SplitWhitespace,
+        kontext: &mut FormelKontext,
+    ) -> Result<ScriptAction, ScriptError> {
+        let funktion = get_funktion(&mut iterator, kontext)?;
+        let ausgabe = match funktion.to_zwei_knf(kontext).and_then(|menge| loese_zwei_sat(&menge)) {
+            Some(ergebnis) => format!("{}", ergebnis),
+            None => String::from("Die Funktion ist keine 2-KNF"),
+        };
+        Ok(Print { ausgabe })
+    }
+
+    fn print_post(
+        mut iterator: SplitWhitespace,
+        kontext: &mut FormelKontext,
+    ) -> Result<ScriptAction, ScriptError> {
+        let funktion = get_funktion(&mut iterator, kontext)?;
+        let klassen = PostKlassen::new(kontext, funktion).map_err(|fehler| PostNotPossible(fehler.to_string()))?;
+        Ok(Print {
+            ausgabe: format!("{}", klassen),
+        })
+    }
+
+    /// `PRINT NAND <NAME> [TEILEN]` und `PRINT NOR <NAME> [TEILEN]`. Mit `TEILEN` bekommen gleiche Teilterme
+    /// nur ein Gatter und die Schaltung wird Gatter für Gatter ausgegeben.
+    fn print_gatter(
+        mut iterator: SplitWhitespace,
+        kontext: &mut FormelKontext,
+        typ: Gattertyp,
+    ) -> Result<ScriptAction, ScriptError> {
+        let funktion = get_funktion(&mut iterator, kontext)?;
+        let schaltung = Schaltung::aus_funktion(kontext, funktion, typ);
+        let ausgabe = match iterator.next() {
+            None => {
+                let form = schaltung.to_funktion();
+                format!("{}\nGatter: {}", form, anzahl_gatter(&form))
+            }
+            Some("teilen") | Some("TEILEN") => {
+                if let Some(other) = iterator.next() {
+                    return Err(ScriptError::WrongSyntax(String::from(other)));
+                }
+                format!("{}\nGatter: {}", schaltung, schaltung.gatter.len())
+            }
+            Some(other) => return Err(ScriptError::WrongSyntax(String::from(other))),
+        };
+        Ok(Print { ausgabe })
+    }
+
+    fn print_tableau(
+        mut iterator: SplitWhitespace,
+        kontext: &mut FormelKontext,
+    ) -> Result<ScriptAction, ScriptError> {
+        let funktion = get_funktion(&mut iterator, kontext)?;
+        Ok(Print {
+            ausgabe: format!("{}", Tableau::new(kontext, funktion)),
+        })
+    }
+
+    fn print_klauseln(
+        mut iterator: SplitWhitespace,
+        kontext: &mut FormelKontext,
+    ) -> Result<ScriptAction, ScriptError> {
+        let funktion = get_funktion(&mut iterator, kontext)?;
+        Ok(Print {
+            ausgabe: format!("{}", funktion.to_knf(kontext)),
+        })
     }
 
     fn print_tabelle(
@@ -269,7 +607,7 @@ mod print {
         Ok(Print {ausgabe: format!("{}", belegung)})
     }
 
-   fn print_aequivalenz(mut iterator: SplitWhitespace,
+   fn print_aequivalenz(iterator: SplitWhitespace,
                                    kontext: &mut FormelKontext,) -> Result<ScriptAction, ScriptError> {
         let  mut vec = Vec::new();
         for name in iterator {
@@ -277,19 +615,18 @@ mod print {
             if  option.is_none() {
                 return Err(FunktionNotFound(String::from(name)))
             }
-            vec.push(option.unwrap());
+            vec.push((name, option.unwrap()));
         }
-        Ok(Print {ausgabe: format!("{}",  is_aequivalent(kontext, vec))})
+        Ok(Print {ausgabe: format!("{}",  pruefe_aequivalenz(kontext, vec))})
     }
 }
 
 mod tabelle {
-    use std::mem::transmute;
     use std::str::SplitWhitespace;
     use crate::aussagen::get_wahrheitstabelle;
 
     use crate::aussagen::structures::FormelKontext;
-    use crate::script::ScriptAction::{GenerateTabelle, Print};
+    use crate::script::ScriptAction::GenerateTabelle;
     use crate::script::{ScriptAction, ScriptError};
     use crate::script::ScriptError::FunktionNotFound;
 
@@ -310,3 +647,277 @@ mod tabelle {
         Ok(GenerateTabelle())
     }
 }
+
+mod minimiere {
+    use std::str::SplitWhitespace;
+    use crate::aussagen::minimierung;
+
+    use crate::aussagen::structures::FormelKontext;
+    use crate::script::ScriptAction::Print;
+    use crate::script::{ScriptAction, ScriptError};
+    use crate::script::ScriptError::FunktionNotFound;
+
+    pub(super) fn minimiere(
+        mut iterator: SplitWhitespace,
+        kontext: &mut FormelKontext,
+    ) -> Result<ScriptAction, ScriptError> {
+        let next = iterator.next();
+        if next.is_none() {
+            return Err(ScriptError::WrongSyntax(String::new()));
+        }
+        let name = next.unwrap();
+        let option = kontext.funktionen.get(name);
+        if option.is_none() {
+            return Err(FunktionNotFound(String::from(name)));
+        }
+        let minimierung = minimierung::minimiere(kontext, option.unwrap())
+            .map_err(|fehler| ScriptError::MinimierungNotPossible(fehler.to_string()))?;
+        Ok(Print {ausgabe: format!("{}", minimierung)})
+    }
+}
+
+mod vereinfache {
+    use std::str::SplitWhitespace;
+
+    use crate::aussagen::structures::FormelKontext;
+    use crate::script::ScriptAction::Print;
+    use crate::script::{ScriptAction, ScriptError};
+    use crate::script::ScriptError::FunktionNotFound;
+
+    pub(super) fn vereinfache(
+        mut iterator: SplitWhitespace,
+        kontext: &mut FormelKontext,
+    ) -> Result<ScriptAction, ScriptError> {
+        let next = iterator.next();
+        if next.is_none() {
+            return Err(ScriptError::WrongSyntax(String::new()));
+        }
+        let name = next.unwrap();
+        match kontext.funktionen.get(name) {
+            None => Err(FunktionNotFound(String::from(name))),
+            Some(funktion) => Ok(Print {
+                ausgabe: format!("{}", funktion.vereinfache()),
+            }),
+        }
+    }
+}
+
+mod beweis {
+    use std::str::SplitWhitespace;
+
+    use crate::aussagen::deduktion::Beweis;
+    use crate::script::ScriptAction::{Gepuffert, Print};
+    use crate::script::{ScriptAction, ScriptError};
+
+    /// `BEWEIS` öffnet einen Block, dessen Zeilen bis `ENDE` gesammelt werden.
+    pub(super) fn beweis(mut iterator: SplitWhitespace) -> Result<ScriptAction, ScriptError> {
+        if iterator.next().is_some() {
+            return Err(ScriptError::WrongSyntax(String::new()));
+        }
+        Ok(Gepuffert())
+    }
+
+    /// Prüft die gesammelten Zeilen eines Blocks Schritt für Schritt.
+    pub(super) fn beweis_pruefen(zeilen: Vec<String>) -> Result<ScriptAction, ScriptError> {
+        if zeilen.iter().all(|zeile| zeile.trim().is_empty()) {
+            return Err(ScriptError::BeweisNotCorrect(String::from("Der Beweis hat keine Zeilen")));
+        }
+        let fehler: Vec<String> = match Beweis::parse(&zeilen.join("\n")) {
+            Ok(beweis) => {
+                let fehler = beweis.pruefe();
+                if fehler.is_empty() {
+                    return Ok(Print {
+                        ausgabe: format!("Beweis korrekt: {}", beweis.sequenz()),
+                    });
+                }
+                fehler.iter().map(|fehler| fehler.to_string()).collect()
+            }
+            Err(fehler) => fehler.iter().map(|fehler| fehler.to_string()).collect(),
+        };
+        Err(ScriptError::BeweisNotCorrect(fehler.join("\n")))
+    }
+}
+
+mod folgert {
+    use std::str::SplitWhitespace;
+
+    use crate::aussagen::folgerung::{self, Folgerung};
+    use crate::aussagen::structures::{werte_to_string, FormelKontext};
+    use crate::script::ScriptAction::Print;
+    use crate::script::{ScriptAction, ScriptError};
+    use crate::script::ScriptError::FunktionNotFound;
+
+    /// `FOLGERT <PRÄMISSEN>... ⊨ <KONKLUSION>`, statt ⊨ kann auch |= geschrieben werden.
+    pub(super) fn folgert(
+        mut iterator: SplitWhitespace,
+        kontext: &mut FormelKontext,
+    ) -> Result<ScriptAction, ScriptError> {
+        let mut praemissen = Vec::new();
+        let mut namen = Vec::new();
+        loop {
+            match iterator.next() {
+                None => return Err(ScriptError::WrongSyntax(String::new())),
+                Some("⊨") | Some("|=") => break,
+                Some(name) => match kontext.funktionen.get(name) {
+                    None => return Err(FunktionNotFound(String::from(name))),
+                    Some(funktion) => {
+                        praemissen.push(funktion);
+                        namen.push(name);
+                    }
+                },
+            }
+        }
+        let name = match (iterator.next(), iterator.next()) {
+            (Some(name), None) => name,
+            _ => return Err(ScriptError::WrongSyntax(String::new())),
+        };
+        let konklusion = match kontext.funktionen.get(name) {
+            None => return Err(FunktionNotFound(String::from(name))),
+            Some(funktion) => funktion,
+        };
+
+        let ausgabe = match folgerung::folgert(kontext, &praemissen, konklusion) {
+            Folgerung::Gueltig => format!("{} ⊨ {}", namen.join(", "), name),
+            Folgerung::Gegenmodell(werte) => format!(
+                "{} ⊭ {}\nGegenmodell: {}",
+                namen.join(", "),
+                name,
+                werte_to_string(&werte)
+            ),
+        };
+        Ok(Print { ausgabe: ausgabe.trim_start().to_string() })
+    }
+}
+
+mod sequenz {
+    use std::str::SplitWhitespace;
+
+    use crate::aussagen::sequenzen::Sequenzbeweis;
+    use crate::aussagen::structures::{werte_to_string, AussagenFunktion, FormelKontext};
+    use crate::script::ScriptAction::Print;
+    use crate::script::{ScriptAction, ScriptError};
+    use crate::script::ScriptError::FunktionNotFound;
+
+    /// `SEQUENZ [LATEX] <LINKS>... ⊢ <RECHTS>...`, statt ⊢ kann auch |- geschrieben werden.
+    pub(super) fn sequenz(
+        iterator: SplitWhitespace,
+        kontext: &mut FormelKontext,
+    ) -> Result<ScriptAction, ScriptError> {
+        let mut iterator = iterator.peekable();
+        let latex = iterator
+            .next_if(|wort| wort.eq_ignore_ascii_case("LATEX"))
+            .is_some();
+        let mut links: Vec<&AussagenFunktion> = Vec::new();
+        let mut rechts: Vec<&AussagenFunktion> = Vec::new();
+        let mut gefunden = false;
+        for name in iterator {
+            match name {
+                "⊢" | "|-" if !gefunden => gefunden = true,
+                name => {
+                    let funktion = match kontext.funktionen.get(name) {
+                        None => return Err(FunktionNotFound(String::from(name))),
+                        Some(funktion) => funktion,
+                    };
+                    if gefunden {
+                        rechts.push(funktion);
+                    } else {
+                        links.push(funktion);
+                    }
+                }
+            }
+        }
+        if !gefunden {
+            return Err(ScriptError::WrongSyntax(String::new()));
+        }
+
+        let beweis = Sequenzbeweis::new(kontext, &links, &rechts);
+        let mut ausgabe = if latex {
+            beweis.to_latex()
+        } else {
+            beweis.to_string().trim_end().to_string()
+        };
+        if let Some(gegenmodell) = beweis.gegenmodell() {
+            ausgabe.push_str(&format!("\nNicht beweisbar, Gegenmodell: {}", werte_to_string(gegenmodell)));
+        }
+        Ok(Print { ausgabe })
+    }
+}
+
+mod vollstaendig {
+    use std::str::SplitWhitespace;
+
+    use crate::aussagen::parsing::parse_function;
+    use crate::aussagen::post::Vollstaendigkeit;
+    use crate::aussagen::structures::{AussagenFunktion, FormelKontext};
+    use crate::script::ScriptAction::Print;
+    use crate::script::{get_rest, ScriptAction, ScriptError};
+
+    /// `VOLLSTAENDIG <FUNKTION>, <FUNKTION>...`. Jede Funktion ist der Name einer gesetzten Funktion
+    /// oder eine Formel über Platzhaltern, z.B. `VOLLSTAENDIG A -> B, f`.
+    pub(super) fn vollstaendig(
+        mut iterator: SplitWhitespace,
+        kontext: &mut FormelKontext,
+    ) -> Result<ScriptAction, ScriptError> {
+        let rest = get_rest(&mut iterator)?;
+        let mut funktionen = Vec::new();
+        for teil in rest.split(',').map(str::trim) {
+            if kontext.funktionen.contains_key(teil) {
+                funktionen.push(AussagenFunktion::VARIABEL(String::from(teil)));
+                continue;
+            }
+            match parse_function(teil) {
+                Ok(funktion) => funktionen.push(*funktion),
+                Err(parse_error) => return Err(ScriptError::ParseNotPossible(String::from(teil), parse_error)),
+            }
+        }
+        let funktionen: Vec<&AussagenFunktion> = funktionen.iter().collect();
+        let vollstaendigkeit = Vollstaendigkeit::new(kontext, &funktionen)
+            .map_err(|fehler| ScriptError::PostNotPossible(fehler.to_string()))?;
+        Ok(Print {
+            ausgabe: format!("{}", vollstaendigkeit),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::aussagen::structures::FormelKontext;
+    use crate::script::{Script, ScriptAction, ScriptError};
+
+    #[test]
+    fn beweis_block_test() {
+        let mut kontext = FormelKontext::new();
+        let mut script = Script::new();
+        let zeilen = [
+            "BEWEIS",
+            "1 A -> B : P",
+            "2 -B : P",
+            "3 | A : A",
+            "4 | B : ->E 1, 3",
+            "5 | f : -E 2, 4",
+            "6 -A : -I 3-5",
+        ];
+        for zeile in zeilen {
+            assert!(matches!(script.parse_line(zeile, &mut kontext), Ok(ScriptAction::Gepuffert())), "{}", zeile);
+        }
+        match script.parse_line("ENDE", &mut kontext) {
+            Ok(ScriptAction::Print { ausgabe }) => assert!(ausgabe.starts_with("Beweis korrekt: "), "{}", ausgabe),
+            ergebnis => panic!("{:?}", ergebnis),
+        }
+        assert!(script.beende().is_ok());
+
+        // Ein leerer Block ist kein Beweis.
+        assert!(matches!(script.parse_line("BEWEIS", &mut kontext), Ok(ScriptAction::Gepuffert())));
+        assert!(matches!(
+            script.parse_line("ENDE", &mut kontext),
+            Err(ScriptError::BeweisNotCorrect(fehler)) if fehler == "Der Beweis hat keine Zeilen"
+        ));
+
+        // Im Block werden auch andere Befehle nur gesammelt, und ohne ENDE gibt es einen Fehler.
+        assert!(matches!(script.parse_line("BEWEIS", &mut kontext), Ok(ScriptAction::Gepuffert())));
+        assert!(matches!(script.parse_line("SET AUSSAGEN phi A", &mut kontext), Ok(ScriptAction::Gepuffert())));
+        assert!(kontext.funktionen.is_empty());
+        assert!(matches!(script.beende(), Err(ScriptError::BeweisNotCorrect(fehler)) if fehler == "ENDE fehlt"));
+        assert!(script.beende().is_ok());
+    }
+}